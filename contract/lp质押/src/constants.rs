@@ -4,6 +4,18 @@ pub static COMPUTATION_DECIMALS: u64 = 10u64.pow(12);
 pub static STAKING_SEED: &[u8] = b"staking_instance";
 // pub static USER_SEED: &[u8] = b"user_deposit";
 pub static LPTOKEN_SEED: &[u8] = b"lp_token";
+// LP 奖励金库种子（接收提前解押未销毁的罚金）
+pub static LP_REWARD_VAULT_SEED: &[u8] = b"lp_reward_vault";
+// 基点分母（10000 = 100%）
+pub const BPS_DENOMINATOR: u64 = 10_000;
+// 旧版 User 账户中内嵌的质押槽位数量（迁移后新仓位编号从该值开始）
+pub const LEGACY_STAKED_SLOTS: usize = 10;
+// 推荐奖励最大层级数（决定 RewardSplit 大小）
+pub const MAX_REFERRAL_LEVELS: usize = 5;
+// 质押池期限上限（月），到期时间按月逐月推算，限制循环次数
+pub const MAX_POOL_DURATION_MONTHS: u64 = 120;
+// 质押池数量上限（决定 StakingInstance 账户空间）
+pub const MAX_STAKING_POOLS: usize = 10;
 // 奖励领取冷却时间 (秒)
 pub static REWARD_CLAIM_COOLDOWN: u64 = 3600; // 1小时
 
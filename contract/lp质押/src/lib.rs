@@ -8,8 +8,12 @@ use anchor_lang::solana_program::program::invoke_signed;
 use constants::*;
 use structures::{
     cancel_staking::*, claim_rewards::*, enter_staking::*, initialize_staking::*,
-    initialize_user::*
+    initialize_user::*, add_pool::*, set_pool_deposit_window::*, set_reward_split::*,
+    set_referral_config::*, early_unstake::*, set_early_unstake_config::*,
+    migrate_user::*, get_user_positions::*, migrate_staking_instance::*,
+    init_lp_reward_vault::*, withdraw_lp_reward_vault::*
 };
+use structures::{PoolConfig, RewardSplit};
 
 declare_id!("FfTLXfiSaB72MRCJH2xtmuV4rXFQi24ubC85kq4LPi1h");
 
@@ -19,19 +23,12 @@ pub mod Stake_Program {
 
     pub fn initialize_staking(
         ctx: Context<InitializeStaking>,
-        reward_per_sec_3_months: u64,
-        reward_per_sec_6_months: u64,
-        reward_per_sec_12_months: u64,
+        pools: Vec<PoolConfig>,
         start_reward_timestamp: u64,
         gdtc_pool_address: Pubkey,
+        reward_split: RewardSplit,
     ) -> Result<()> {
-        ctx.accounts.process(
-            reward_per_sec_3_months,
-            reward_per_sec_6_months,
-            reward_per_sec_12_months,
-            start_reward_timestamp,
-            gdtc_pool_address
-        )
+        ctx.accounts.process(pools, start_reward_timestamp, gdtc_pool_address, reward_split)
     }
 
  pub fn initialize_user(ctx: Context<InitializeUser>,_user_superior_account:Pubkey) -> Result<()> {
@@ -43,24 +40,108 @@ pub mod Stake_Program {
         ctx: Context<EnterStaking>,
         lp_staking_number: u64,
         stake_type: u64,
-        staked_info_index: u64,
     ) -> Result<()> {
-        ctx.accounts.process(lp_staking_number, stake_type, staked_info_index)
+        ctx.accounts.process(lp_staking_number, stake_type)
     }
 
-    pub fn cancel_staking(ctx: Context<CancelStaking>, staked_info_index: u64) -> Result<()> {
+    pub fn cancel_staking(ctx: Context<CancelStaking>, position_id: u64) -> Result<()> {
 
         let bump_seed = ctx.bumps.staking_instance;
-    ctx.accounts.process(staked_info_index,bump_seed)
+    ctx.accounts.process(position_id,bump_seed)
 }
 
 
-    pub fn claim_rewards(ctx: Context<ClaimRewards>, staked_info_index: u64) -> Result<()> {
+    pub fn claim_rewards<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ClaimRewards<'info>>,
+        position_id: u64,
+    ) -> Result<()> {
 
         let bump_seed = ctx.bumps.staking_instance;
-    ctx.accounts.process(staked_info_index,bump_seed)
+    ctx.accounts.process(position_id, bump_seed, ctx.remaining_accounts)
 }
 
+    pub fn add_pool(
+        ctx: Context<AddPool>,
+        duration_months: u64,
+        reward_per_sec: u64,
+        deposit_open_timestamp: u64,
+        deposit_close_timestamp: u64,
+    ) -> Result<()> {
+        ctx.accounts.process(duration_months, reward_per_sec, deposit_open_timestamp, deposit_close_timestamp)
+    }
+
+    pub fn set_pool_deposit_window(
+        ctx: Context<SetPoolDepositWindow>,
+        stake_type: u64,
+        deposit_open_timestamp: u64,
+        deposit_close_timestamp: u64,
+    ) -> Result<()> {
+        ctx.accounts.process(stake_type, deposit_open_timestamp, deposit_close_timestamp)
+    }
+
+    pub fn set_reward_split(ctx: Context<SetRewardSplit>, reward_split: RewardSplit) -> Result<()> {
+        ctx.accounts.process(reward_split)
+    }
+
+    pub fn set_referral_config(
+        ctx: Context<SetReferralConfig>,
+        min_referral_stake: u64,
+        referral_fallback_address: Pubkey,
+    ) -> Result<()> {
+        ctx.accounts.process(min_referral_stake, referral_fallback_address)
+    }
+
+    pub fn early_unstake(ctx: Context<EarlyUnstake>, position_id: u64) -> Result<()> {
+
+        let bump_seed = ctx.bumps.staking_instance;
+        ctx.accounts.process(position_id, bump_seed)
+    }
+
+    pub fn set_early_unstake_config(
+        ctx: Context<SetEarlyUnstakeConfig>,
+        early_unstake_penalty_bps: u16,
+        penalty_burn_bps: u16,
+    ) -> Result<()> {
+        ctx.accounts.process(early_unstake_penalty_bps, penalty_burn_bps)
+    }
+
+    pub fn init_lp_reward_vault(ctx: Context<InitLpRewardVault>) -> Result<()> {
+        ctx.accounts.process()
+    }
+
+    pub fn withdraw_lp_reward_vault(ctx: Context<WithdrawLpRewardVault>, amount: u64) -> Result<()> {
+
+        let bump_seed = ctx.bumps.staking_instance;
+        ctx.accounts.process(amount, bump_seed)
+    }
+
+    pub fn migrate_staking_instance(
+        ctx: Context<MigrateStakingInstance>,
+        deposit_open_timestamp: u64,
+        deposit_close_3_months: u64,
+        deposit_close_6_months: u64,
+        deposit_close_12_months: u64,
+        reward_split: RewardSplit,
+    ) -> Result<()> {
+        ctx.accounts.process(
+            deposit_open_timestamp,
+            deposit_close_3_months,
+            deposit_close_6_months,
+            deposit_close_12_months,
+            reward_split
+        )
+    }
+
+    pub fn migrate_user<'info>(ctx: Context<'_, '_, 'info, 'info, MigrateUser<'info>>) -> Result<()> {
+        ctx.accounts.process(ctx.remaining_accounts)
+    }
+
+    pub fn get_user_positions<'info>(
+        ctx: Context<'_, '_, 'info, 'info, GetUserPositions<'info>>,
+    ) -> Result<Vec<PositionInfo>> {
+        ctx.accounts.process(ctx.remaining_accounts)
+    }
+
     // pub fn admin_claim_rewards(ctx: Context<AdminClaimRewards>, claim_number: u64) -> Result<()> {
     // ctx.accounts.process(claim_number)
     // }
@@ -0,0 +1,69 @@
+use anchor_lang::prelude::*;
+
+use super::errors::StakingError;
+use super::*;
+use crate::structures::utils::update_reward_pool;
+
+#[derive(Accounts)]
+pub struct AddPool<'info> {
+    /// 全局质押状态
+    #[account(
+        mut,
+        seeds = [crate::constants::STAKING_SEED],
+        bump,
+        constraint = staking_instance.is_initialized @ StakingError::StakingInstanceNotInitialized,
+        constraint = staking_instance.authority == authority.key() @ StakingError::Unauthorized
+    )]
+    pub staking_instance: Account<'info, StakingInstance>,
+
+    /// 管理员
+    pub authority: Signer<'info>,
+}
+
+impl<'info> AddPool<'info> {
+    pub fn process(
+        &mut self,
+        duration_months: u64,
+        reward_per_sec: u64,
+        deposit_open_timestamp: u64,
+        deposit_close_timestamp: u64,
+    ) -> Result<()> {
+        let staking_instance = &mut self.staking_instance;
+
+        PoolConfig {
+            duration_months,
+            reward_per_sec,
+            deposit_open_timestamp,
+            deposit_close_timestamp,
+        }
+        .validate()?;
+        require!(
+            staking_instance.pools.len() < crate::constants::MAX_STAKING_POOLS,
+            StakingError::PoolIsFull
+        );
+
+        let clock = Clock::get()?;
+        let current_timestamp = clock.unix_timestamp as u64;
+
+        // 先结算已有池子的奖励，新池子从当前时间开始计算奖励
+        update_reward_pool(current_timestamp, staking_instance);
+
+        let stake_type = staking_instance.pools.len() as u64;
+        staking_instance.pools.push(StakingPool::new(
+            stake_type,
+            duration_months,
+            reward_per_sec,
+            current_timestamp,
+            deposit_open_timestamp,
+            deposit_close_timestamp,
+        ));
+
+        msg!(
+            "Pool {} added: {} months, {} reward per second",
+            stake_type,
+            duration_months,
+            reward_per_sec
+        );
+        Ok(())
+    }
+}
@@ -4,11 +4,12 @@ use anchor_spl::token::{self, Token, TokenAccount,Transfer};
 use crate::constants::*;
 
 use super::errors::StakingError;
-use crate::structures::utils::{update_reward_pool, update_reward_debt, store_pending_reward};
+use crate::structures::utils::{update_reward_pool, store_pending_reward};
 
 use super::*;
 
 #[derive(Accounts)]
+#[instruction(position_id: u64)]
 pub struct CancelStaking<'info> {
     #[account(mut)]
     pub authority: Signer<'info>, //签名用户
@@ -24,11 +25,22 @@ pub struct CancelStaking<'info> {
     #[account(
         mut,
         seeds = [b"user", authority.key().as_ref()],
+        constraint = user_instance.to_account_info().data_len() == User::LEN @ StakingError::UserNotMigrated,
         constraint = user_instance.isinit @ StakingError::UserNotInitialized,
         bump
     )]
     pub user_instance: Box<Account<'info, User>>,
 
+    /// 要解除的质押仓位，解除后关闭并退还租金
+    #[account(
+        mut,
+        seeds = [b"stake_position", authority.key().as_ref(), position_id.to_le_bytes().as_ref()],
+        constraint = stake_position.owner == authority.key() @ StakingError::UserAccountIsNotMatch,
+        bump,
+        close = authority
+    )]
+    pub stake_position: Box<Account<'info, StakePosition>>,
+
     /// 用户 LP Token 接收账户
     #[account(
         mut,
@@ -41,22 +53,23 @@ pub struct CancelStaking<'info> {
     #[account(
         mut,
         constraint = gdtc_lp_in_account.mint == staking_instance.staking_token_mint @ StakingError::MintAccountIsNotMatch,
-        constraint = gdtc_lp_in_account.owner == staking_instance.key() @ StakingError::InvalidLpTokenOwner
+        constraint = gdtc_lp_in_account.owner == staking_instance.key() @ StakingError::InvalidLpTokenOwner,
+        constraint = gdtc_lp_in_account.key() != staking_instance.lp_reward_vault @ StakingError::InvalidLpRewardVault
     )]
     pub gdtc_lp_in_account: Account<'info, TokenAccount>,
     pub token_program: Program<'info, Token>,
 }
 
 impl<'info> CancelStaking<'info> {
-    pub fn process(&mut self, staked_info_index: u64,bump_seed:u8) -> Result<()> {
+    pub fn process(&mut self, position_id: u64,bump_seed:u8) -> Result<()> {
        
         let user_instance = &mut self.user_instance;
+        let staked_info = &mut self.stake_position.staked;
 
-        let index = staked_info_index as usize;
-        let amount = user_instance.staked_info[index].deposited_amount;
+        let amount = staked_info.deposited_amount;
 
         // 检查是否质押
-        if !user_instance.staked_info[index].is_staked {
+        if !staked_info.is_staked {
             return Err(StakingError::NoStakingToCancel.into());
         }
 
@@ -64,10 +77,10 @@ impl<'info> CancelStaking<'info> {
         let current_timestamp = clock.unix_timestamp as u64;
 
         // 检查质押是否到期
-        if current_timestamp < user_instance.staked_info[index].stake_end_time {
+        if current_timestamp < staked_info.stake_end_time {
             return Err(StakingError::StakingNotMatured.into());
         }
-        if !user_instance.staked_info[index].can_cancel_stake {
+        if !staked_info.can_cancel_stake {
             return Err(StakingError::NeedCliamRewards.into());
         }
 
@@ -75,30 +88,25 @@ impl<'info> CancelStaking<'info> {
          let staking_instance = &mut self.staking_instance;
         // 更新奖励池并计算待领取奖励
         update_reward_pool(current_timestamp, staking_instance);
-        store_pending_reward(staking_instance, user_instance, staked_info_index)?;
+        store_pending_reward(staking_instance, staked_info)?;
 
 
 
         // 更新质押池份额
-        let pool = &mut staking_instance.pools[user_instance.staked_info[index].stake_type as usize];
+        let pool = &mut staking_instance.pools[staked_info.stake_type as usize];
         pool.total_shares = pool.total_shares.checked_sub(amount).ok_or(StakingError::Underflow)?;
 
-        // 更新奖励债务
-        // update_reward_debt(staking_instance, user_instance, staked_info_index);
-
-        // 重置用户质押状态
-        let staked_info = &mut user_instance.staked_info[index];
+        // 重置仓位质押状态（账户在指令结束时关闭）
         staked_info.deposited_amount = 0;
         staked_info.accumulated_reward = 0;
         staked_info.is_staked = false;
-        staked_info.stake_type = 0;
-        staked_info.reward_debt = 0;
-        staked_info.stake_start_time = 0;
-        staked_info.stake_end_time = 0;
-        staked_info.receivedReward = 0;
-        staked_info.can_cancel_stake = false;
 
+        user_instance.active_positions = user_instance
+            .active_positions
+            .checked_sub(1)
+            .ok_or(StakingError::Underflow)?;
 
+        msg!("Stake position {} cancelled, {} LP returned", position_id, amount);
 
         // PDA 签名转账 LP Token 回给用户
         // let bump_seed = self.bump; // Anchor 自动提供 bumps
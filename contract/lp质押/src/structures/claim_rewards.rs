@@ -1,14 +1,20 @@
 use anchor_lang::prelude::*;
 // use anchor_spl::token::{self, Token, TokenAccount};
-use anchor_spl::token::{self, Token, TokenAccount, Mint, Transfer, Burn};
+use anchor_spl::token::{Token, TokenAccount, Mint};
 
 use std::str::FromStr;
 
 use super::*;
-use crate::structures::utils::{update_reward_pool, store_pending_reward};
+use crate::structures::utils::{
+    update_reward_pool, store_pending_reward, transfer_from_staking_vault,
+    load_upline_user, load_upline_token_account,
+};
 use super::errors::StakingError;
 
+/// 各级上级通过 remaining_accounts 按 [上级 User 账户, 上级奖励代币账户] 成对传入，
+/// 第一对为直接上级，依次向上
 #[derive(Accounts)]
+#[instruction(position_id: u64)]
 pub struct ClaimRewards<'info> {
     #[account(mut)]
     pub authority: Signer<'info>, //签名用户
@@ -24,20 +30,22 @@ pub struct ClaimRewards<'info> {
     #[account(
         mut,
         seeds = [b"user", authority.key().as_ref()],
+        constraint = user_instance.to_account_info().data_len() == User::LEN @ StakingError::UserNotMigrated,
         constraint = user_instance.isinit @ StakingError::UserAlreadyStaked,
         constraint = user_instance.user_address == authority.key() @ StakingError::UserAccountIsNotMatch,
         bump
     )]
     pub user_instance: Box<Account<'info, User>>,
 
-
-      /// 上级的 GDTC Token 账户（用于转发或发奖励）
+    /// 质押仓位
     #[account(
         mut,
-        constraint = user_super_gdtc_token_account.mint == staking_instance.reward_token_mint @ StakingError::MintAccountIsNotMatch,
-        constraint = user_super_gdtc_token_account.owner == user_instance.user_superior_account.key() @ StakingError::InvalidVaultOwner
+        seeds = [b"stake_position", authority.key().as_ref(), position_id.to_le_bytes().as_ref()],
+        constraint = stake_position.owner == authority.key() @ StakingError::UserAccountIsNotMatch,
+        bump
     )]
-    pub user_super_gdtc_token_account: Account<'info, TokenAccount>,
+    pub stake_position: Box<Account<'info, StakePosition>>,
+
 
     /// 用户的 GDTC Token 账户（用于接收奖励）
     #[account(
@@ -56,10 +64,18 @@ pub struct ClaimRewards<'info> {
 
 
 
+    //上级不满足条件时推荐奖励的接收账户
+    #[account(mut,
+        constraint = referral_fallback_token_account.mint == staking_instance.reward_token_mint @ StakingError::MintAccountIsNotMatch,
+        constraint = referral_fallback_token_account.owner == staking_instance.referral_fallback_address @ StakingError::InvalidVaultOwner
+    )]
+    pub referral_fallback_token_account: Account<'info, TokenAccount>,
+
     //黑洞地址tokenaccount
     #[account(
         mut,
         constraint = black_hole_bio_account.mint == staking_instance.reward_token_mint @ StakingError::MintAccountIsNotMatch,
+        constraint = black_hole_bio_account.owner == Pubkey::from_str("11111111111111111111111111111111").unwrap() @ StakingError::InvalidVaultOwner,
     )]
     pub black_hole_bio_account: Account<'info, TokenAccount>,
 
@@ -85,17 +101,20 @@ pub struct ClaimRewards<'info> {
 
 impl<'info> ClaimRewards<'info> {
 
-    pub fn process(&mut self, staked_info_index: u64,bump_seed:u8) -> Result<()> {
-
-        msg!("start claim rewards");
-        msg!("staked_info_index: {:?}", staked_info_index);
-
+    pub fn process(
+        &mut self,
+        position_id: u64,
+        bump_seed: u8,
+        remaining_accounts: &'info [AccountInfo<'info>],
+    ) -> Result<()> {
 
         // 获取当前时间戳
         let staking_instance = &mut self.staking_instance;
 
         let user_instance = &mut self.user_instance;
 
+        let staked_info = &mut self.stake_position.staked;
+
 
         let gdtc_reward_out_account = &self.gdtc_reward_out_account;
 
@@ -104,30 +123,16 @@ impl<'info> ClaimRewards<'info> {
         // 时间戳和用户校验
         let clock = Clock::get()?;
         let current_timestamp = clock.unix_timestamp as u64;
-        let index = staked_info_index as usize;
-
-
-        msg!("user_instance.staked_info[index].is_staked: {:?}", user_instance.staked_info[index].is_staked);
-        msg!("user_instance.staked_info[index].accumulated_reward: {:?}", user_instance.staked_info[index].accumulated_reward);
-        msg!("user_instance.staked_info[index].receivedReward: {:?}", user_instance.staked_info[index].receivedReward);
-        msg!("user_instance.staked_info[index].can_cancel_stake: {:?}", user_instance.staked_info[index].can_cancel_stake);
-        msg!("user_instance.staked_info[index].deposited_amount: {:?}", user_instance.staked_info[index].deposited_amount);
-        msg!("user_instance.staked_info[index].reward_debt: {:?}", user_instance.staked_info[index].reward_debt);
-        msg!("user_instance.staked_info[index].stake_end_time: {:?}", user_instance.staked_info[index].stake_end_time);
-        msg!("user_instance.staked_info[index].stake_start_time: {:?}", user_instance.staked_info[index].stake_start_time);
-        msg!("user_instance.staked_info[index].stake_type: {:?}", user_instance.staked_info[index].stake_type);
-        msg!("user_instance.staked_info[index].stake_start_time: {:?}", user_instance.staked_info[index].stake_start_time);
-        
-        
-        if !user_instance.staked_info[index].is_staked {
+
+        if !staked_info.is_staked {
             return Err(StakingError::NoStakingToClaimRewards.into());
         }
 
         // 更新奖励池并计算用户奖励
         update_reward_pool(current_timestamp, staking_instance);
-        store_pending_reward(staking_instance, user_instance, staked_info_index)?;
+        store_pending_reward(staking_instance, staked_info)?;
 
-        let mut accumulated_reward = user_instance.staked_info[index].accumulated_reward;
+        let accumulated_reward = staked_info.accumulated_reward;
         
         // accumulated_reward = 10000;
         if accumulated_reward == 0 {
@@ -144,14 +149,14 @@ impl<'info> ClaimRewards<'info> {
 
         // 检查奖励账户余额
         if gdtc_reward_out_account.amount < accumulated_reward {
-            if current_timestamp >= user_instance.staked_info[index].stake_end_time
+            if current_timestamp >= staked_info.stake_end_time
                 && user_instance.user_address == self.authority.key()
             {
-                if !user_instance.staked_info[index].can_cancel_stake {
-                    user_instance.staked_info[index].can_cancel_stake = true;
+                if !staked_info.can_cancel_stake {
+                    staked_info.can_cancel_stake = true;
                     user_instance.total_deposited_amount = user_instance
                         .total_deposited_amount
-                        .checked_sub(user_instance.staked_info[index].deposited_amount)
+                        .checked_sub(staked_info.deposited_amount)
                         .ok_or(StakingError::Overflow)?;
                 }
             }
@@ -159,119 +164,130 @@ impl<'info> ClaimRewards<'info> {
         }
 
         {
-        let staking_instance = &mut self.staking_instance;
+        let staking_instance = &self.staking_instance;
         let signer_seeds: &[&[&[u8]]] = &[&[crate::STAKING_SEED.as_ref(), &[bump_seed]]];
 
-        // 给上级分红（如果满足条件）
-        // if super_instance.total_deposited_amount > 2_000_000_000 {
-        let cpi_accounts = Transfer {
-            from: self.gdtc_reward_out_account.to_account_info(),
-            to: self.user_super_gdtc_token_account.to_account_info(),
-            authority: staking_instance.to_account_info(),
-        };
-        let cpi_ctx = CpiContext::new_with_signer(
-            self.token_program.to_account_info(),
-            cpi_accounts,
-            signer_seeds,
-        );
-        token::transfer(cpi_ctx, accumulated_reward * 5 / 100)?;
-        // }
-
-        //给全网分红池分红（如果满足条件）
-        if staking_instance.gdtc_pool_address.key() == self.user_global_pool_token_account.owner {
-            let cpi_accounts = Transfer {
-                from: self.gdtc_reward_out_account.to_account_info(),
-                to: self.user_global_pool_token_account.to_account_info(),
-                authority: staking_instance.to_account_info(),
+        // 按管理员配置的比例一次性拆分奖励，各部分之和等于 accumulated_reward
+        let shares = staking_instance.reward_split.split(accumulated_reward)?;
+
+        // 逐级给上级分红。上级存在时必须传入对应账户；
+        // 上级不存在（链路结束或出现循环推荐）或质押量不足时，该级奖励转给 referral_fallback_address；
+        // 之后各级比例都为 0 时停止遍历，不再要求传入更高层级的账户
+        let mut fallback_amount: u64 = 0;
+        let mut visited = vec![self.authority.key()];
+        let mut upline = user_instance.user_superior_account;
+        let mut upline_pairs = remaining_accounts.chunks(2);
+        let active_levels = staking_instance.reward_split.active_referral_levels();
+        for level_share in shares.referral[..active_levels].iter() {
+            if upline == Pubkey::default() || visited.contains(&upline) {
+                upline = Pubkey::default();
+                fallback_amount = fallback_amount
+                    .checked_add(*level_share)
+                    .ok_or(StakingError::Overflow)?;
+                continue;
+            }
+            let (upline_user_info, upline_token_info) = match upline_pairs.next() {
+                Some([user_info, token_info]) => (user_info, token_info),
+                _ => return Err(StakingError::InvalidReferralAccount.into()),
             };
-            let cpi_ctx = CpiContext::new_with_signer(
-                self.token_program.to_account_info(),
-                cpi_accounts,
-                signer_seeds,
-            );
-            token::transfer(cpi_ctx, accumulated_reward *5/ 100)?;
-        }
-
-
 
-        // 给黑洞地址转账10%
-      
-        let black_hole = Pubkey::from_str("11111111111111111111111111111111").unwrap();
-        if  black_hole== self.black_hole_bio_account.owner {
-            let cpi_accounts = Transfer {
-                from: self.gdtc_reward_out_account.to_account_info(),
-                to: self.black_hole_bio_account.to_account_info(),
-                authority: staking_instance.to_account_info(),
+            // 上级没有用户账户时无法继续向上，视为链路结束
+            let upline_instance = match load_upline_user(upline_user_info, upline)? {
+                Some(upline_instance) => upline_instance,
+                None => {
+                    upline = Pubkey::default();
+                    fallback_amount = fallback_amount
+                        .checked_add(*level_share)
+                        .ok_or(StakingError::Overflow)?;
+                    continue;
+                }
             };
-            let cpi_ctx = CpiContext::new_with_signer(
-                self.token_program.to_account_info(),
-                cpi_accounts,
-                signer_seeds,
-            );
-            token::transfer(cpi_ctx, accumulated_reward *10/ 100)?;
-        }
+            load_upline_token_account(upline_token_info, upline, staking_instance.reward_token_mint)?;
+
+            if upline_instance.total_deposited_amount >= staking_instance.min_referral_stake {
+                transfer_from_staking_vault(
+                    &self.token_program,
+                    &self.gdtc_reward_out_account,
+                    upline_token_info.clone(),
+                    staking_instance.to_account_info(),
+                    signer_seeds,
+                    *level_share,
+                )?;
+            } else {
+                fallback_amount = fallback_amount
+                    .checked_add(*level_share)
+                    .ok_or(StakingError::Overflow)?;
+            }
 
+            visited.push(upline);
+            upline = upline_instance.user_superior_account;
+        }
 
-        //给上级再转10额外分红
-        let cpi_accounts = Transfer {
-            from: self.gdtc_reward_out_account.to_account_info(),
-            to: self.user_super_gdtc_token_account.to_account_info(),
-            authority: staking_instance.to_account_info(),
-        };
-        let cpi_ctx = CpiContext::new_with_signer(
-            self.token_program.to_account_info(),
-            cpi_accounts,
+        // 未发给上级的推荐奖励合并一次转给备用地址
+        transfer_from_staking_vault(
+            &self.token_program,
+            &self.gdtc_reward_out_account,
+            self.referral_fallback_token_account.to_account_info(),
+            staking_instance.to_account_info(),
             signer_seeds,
-        );
-        token::transfer(cpi_ctx, accumulated_reward * 10 / 100)?;
-
-
-        
-        //给用户发放80%
-        let cpi_accounts = Transfer {
-            from: self.gdtc_reward_out_account.to_account_info(),
-            to: self.user_gdtc_token_account.to_account_info(),
-            authority: staking_instance.to_account_info(),
-        };
-        let cpi_ctx = CpiContext::new_with_signer(
-            self.token_program.to_account_info(),
-            cpi_accounts,
+            fallback_amount,
+        )?;
+
+        //给全网分红池分红
+        transfer_from_staking_vault(
+            &self.token_program,
+            &self.gdtc_reward_out_account,
+            self.user_global_pool_token_account.to_account_info(),
+            staking_instance.to_account_info(),
+            signer_seeds,
+            shares.global_pool,
+        )?;
+
+        // 给黑洞地址转账（销毁）
+        transfer_from_staking_vault(
+            &self.token_program,
+            &self.gdtc_reward_out_account,
+            self.black_hole_bio_account.to_account_info(),
+            staking_instance.to_account_info(),
+            signer_seeds,
+            shares.burn,
+        )?;
+
+        //给用户发放按比例计算的部分
+        transfer_from_staking_vault(
+            &self.token_program,
+            &self.gdtc_reward_out_account,
+            self.user_gdtc_token_account.to_account_info(),
+            staking_instance.to_account_info(),
             signer_seeds,
-        );
-        token::transfer(cpi_ctx, accumulated_reward * 80 / 100 )?;
+            shares.user,
+        )?;
     }
 
 
 
 
         // 更新质押状态
-        if current_timestamp >= user_instance.staked_info[index].stake_end_time {
-            if user_instance.staked_info[index].can_cancel_stake {
+        if current_timestamp >= staked_info.stake_end_time {
+            if staked_info.can_cancel_stake {
                 return Err(StakingError::NoRewardsToClaim.into());
             }
         
-            user_instance.staked_info[index].can_cancel_stake = true;
+            staked_info.can_cancel_stake = true;
             user_instance.total_deposited_amount = user_instance
                 .total_deposited_amount
-                .checked_sub(user_instance.staked_info[index].deposited_amount)
+                .checked_sub(staked_info.deposited_amount)
                 .ok_or(StakingError::Overflow)?;
         }
 
-        //打印用户信息
-        msg!("用户信息: {:?}", user_instance.user_address);
-        msg!("奖励信息: {:?}", accumulated_reward);
-        msg!("奖励时间: {:?}", current_timestamp);
-        msg!("奖励结束时间: {:?}", user_instance.staked_info[index].stake_end_time);
-        msg!("奖励是否可取消: {:?}", user_instance.staked_info[index].can_cancel_stake);
         // 重置累计奖励并更新已领取记录
-        user_instance.staked_info[index].accumulated_reward = 0;
-        user_instance.staked_info[index].receivedReward = user_instance.staked_info[index]
+        staked_info.accumulated_reward = 0;
+        staked_info.receivedReward = staked_info
             .receivedReward
             .checked_add(accumulated_reward)
             .ok_or(StakingError::Overflow)?;
         
-        msg!("已领取奖励: {:?}", user_instance.staked_info[index].receivedReward);
-        msg!("已质押信息: {:?}", user_instance.staked_info[index]);
+        msg!("Position {} claimed {} rewards", position_id, accumulated_reward);
         Ok(())
     }
 }
\ No newline at end of file
@@ -0,0 +1,207 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Mint, Transfer, Burn};
+
+use crate::constants::*;
+
+use super::errors::StakingError;
+use crate::structures::utils::{update_reward_pool, calculate_early_unstake_penalty_bps};
+
+use super::*;
+
+#[derive(Accounts)]
+#[instruction(position_id: u64)]
+pub struct EarlyUnstake<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>, //签名用户
+    #[account(
+        mut,
+        seeds = [crate::constants::STAKING_SEED],
+        bump,
+        constraint = staking_instance.is_initialized @ StakingError::StakingInstanceNotInitialized
+    )]
+    pub staking_instance: Account<'info, StakingInstance>,
+
+    /// 用户信息
+    #[account(
+        mut,
+        seeds = [b"user", authority.key().as_ref()],
+        constraint = user_instance.to_account_info().data_len() == User::LEN @ StakingError::UserNotMigrated,
+        constraint = user_instance.isinit @ StakingError::UserNotInitialized,
+        bump
+    )]
+    pub user_instance: Box<Account<'info, User>>,
+
+    /// 要提前解除的质押仓位，解除后关闭并退还租金
+    #[account(
+        mut,
+        seeds = [b"stake_position", authority.key().as_ref(), position_id.to_le_bytes().as_ref()],
+        constraint = stake_position.owner == authority.key() @ StakingError::UserAccountIsNotMatch,
+        bump,
+        close = authority
+    )]
+    pub stake_position: Box<Account<'info, StakePosition>>,
+
+    /// 用户 LP Token 接收账户
+    #[account(
+        mut,
+        constraint = user_lp_token_account.mint == staking_instance.staking_token_mint @ StakingError::MintAccountIsNotMatch,
+        constraint = user_lp_token_account.owner == authority.key() @ StakingError::InvalidLpTokenOwner
+    )]
+    pub user_lp_token_account: Account<'info, TokenAccount>,
+
+    /// 合约 LP Token Vault（接收用户质押的 LP）
+    #[account(
+        mut,
+        constraint = gdtc_lp_in_account.mint == staking_instance.staking_token_mint @ StakingError::MintAccountIsNotMatch,
+        constraint = gdtc_lp_in_account.owner == staking_instance.key() @ StakingError::InvalidLpTokenOwner,
+        constraint = gdtc_lp_in_account.key() != staking_instance.lp_reward_vault @ StakingError::InvalidLpRewardVault
+    )]
+    pub gdtc_lp_in_account: Account<'info, TokenAccount>,
+
+    /// LP 奖励金库（接收未销毁的罚金），必须是质押实例中记录的地址，管理员可通过 withdraw_lp_reward_vault 提取
+    #[account(
+        mut,
+        address = staking_instance.lp_reward_vault @ StakingError::InvalidLpRewardVault
+    )]
+    pub lp_reward_vault: Account<'info, TokenAccount>,
+
+    /// LP 代币 mint（销毁罚金用）
+    #[account(
+        mut,
+        constraint = lp_mint_account.key() == staking_instance.staking_token_mint @ StakingError::InvalidLpTokenMint
+    )]
+    pub lp_mint_account: Account<'info, Mint>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> EarlyUnstake<'info> {
+    pub fn process(&mut self, position_id: u64, bump_seed: u8) -> Result<()> {
+        let user_instance = &mut self.user_instance;
+        let staked_info = &mut self.stake_position.staked;
+        let staking_instance = &mut self.staking_instance;
+
+        let amount = staked_info.deposited_amount;
+
+        // 检查是否质押
+        if !staked_info.is_staked {
+            return Err(StakingError::NoStakingToCancel.into());
+        }
+        require!(
+            staking_instance.early_unstake_penalty_bps > 0,
+            StakingError::EarlyUnstakeDisabled
+        );
+
+        let clock = Clock::get()?;
+        let current_timestamp = clock.unix_timestamp as u64;
+
+        // 已到期的仓位走 cancel_staking，不收罚金
+        if current_timestamp >= staked_info.stake_end_time {
+            return Err(StakingError::StakingAlreadyMatured.into());
+        }
+
+        // 先按当前份额结算奖励池，再移除该仓位份额，保证其他仓位的奖励计算正确；
+        // 该仓位未领取的奖励（accumulated_reward 和 reward_debt 之后的部分）全部作废，留在奖励金库
+        update_reward_pool(current_timestamp, staking_instance);
+        let pool = &mut staking_instance.pools[staked_info.stake_type as usize];
+        pool.total_shares = pool.total_shares.checked_sub(amount).ok_or(StakingError::Underflow)?;
+
+        // 计算罚金：比例随剩余质押时间线性递减
+        let penalty_bps = calculate_early_unstake_penalty_bps(
+            staking_instance.early_unstake_penalty_bps,
+            staked_info.stake_start_time,
+            staked_info.stake_end_time,
+            current_timestamp,
+        )?;
+        let penalty_amount = ((amount as u128)
+            .checked_mul(penalty_bps as u128)
+            .ok_or(StakingError::Overflow)?
+            / BPS_DENOMINATOR as u128) as u64;
+        let burn_amount = ((penalty_amount as u128)
+            .checked_mul(staking_instance.penalty_burn_bps as u128)
+            .ok_or(StakingError::Overflow)?
+            / BPS_DENOMINATOR as u128) as u64;
+        let vault_amount = penalty_amount
+            .checked_sub(burn_amount)
+            .ok_or(StakingError::Underflow)?;
+        let return_amount = amount
+            .checked_sub(penalty_amount)
+            .ok_or(StakingError::Underflow)?;
+
+        // 未到期的仓位仍计入用户总质押量，解除时扣减
+        if !staked_info.can_cancel_stake {
+            user_instance.total_deposited_amount = user_instance
+                .total_deposited_amount
+                .checked_sub(amount)
+                .ok_or(StakingError::Underflow)?;
+        }
+
+        // 重置仓位质押状态（账户在指令结束时关闭）
+        staked_info.deposited_amount = 0;
+        staked_info.accumulated_reward = 0;
+        staked_info.is_staked = false;
+
+        user_instance.active_positions = user_instance
+            .active_positions
+            .checked_sub(1)
+            .ok_or(StakingError::Underflow)?;
+
+        msg!(
+            "Stake position {} unstaked early: {} LP returned, penalty {} ({} bps), {} burned",
+            position_id,
+            return_amount,
+            penalty_amount,
+            penalty_bps,
+            burn_amount
+        );
+
+        let signer_seeds: &[&[&[u8]]] = &[&[crate::STAKING_SEED.as_ref(), &[bump_seed]]];
+
+        // 返还扣除罚金后的 LP
+        if return_amount > 0 {
+            let cpi_accounts = Transfer {
+                from: self.gdtc_lp_in_account.to_account_info(),
+                to: self.user_lp_token_account.to_account_info(),
+                authority: self.staking_instance.to_account_info(),
+            };
+            let cpi_ctx = CpiContext::new_with_signer(
+                self.token_program.to_account_info(),
+                cpi_accounts,
+                signer_seeds,
+            );
+            token::transfer(cpi_ctx, return_amount)?;
+        }
+
+        // 未销毁的罚金转入 LP 奖励金库
+        if vault_amount > 0 {
+            let cpi_accounts = Transfer {
+                from: self.gdtc_lp_in_account.to_account_info(),
+                to: self.lp_reward_vault.to_account_info(),
+                authority: self.staking_instance.to_account_info(),
+            };
+            let cpi_ctx = CpiContext::new_with_signer(
+                self.token_program.to_account_info(),
+                cpi_accounts,
+                signer_seeds,
+            );
+            token::transfer(cpi_ctx, vault_amount)?;
+        }
+
+        // 销毁罚金中的销毁部分
+        if burn_amount > 0 {
+            let cpi_accounts = Burn {
+                mint: self.lp_mint_account.to_account_info(),
+                from: self.gdtc_lp_in_account.to_account_info(),
+                authority: self.staking_instance.to_account_info(),
+            };
+            let cpi_ctx = CpiContext::new_with_signer(
+                self.token_program.to_account_info(),
+                cpi_accounts,
+                signer_seeds,
+            );
+            token::burn(cpi_ctx, burn_amount)?;
+        }
+
+        Ok(())
+    }
+}
@@ -7,6 +7,7 @@ use super::*;
 use crate::structures::tools::generate_release_timestamps;
 use crate::structures::utils::update_reward_pool;
 use crate::structures::utils::update_reward_debt;
+use crate::structures::utils::validate_stake_type;
 
 
 #[derive(Accounts)]
@@ -24,11 +25,22 @@ pub struct EnterStaking<'info> {
     #[account(
         mut,
         seeds = [b"user", authority.key().as_ref()],
+        constraint = user_instance.to_account_info().data_len() == User::LEN @ StakingError::UserNotMigrated,
         constraint = user_instance.isinit @ StakingError::UserAlreadyStaked,
         bump
     )]
     pub user_instance: Box<Account<'info, User>>,
 
+    /// 新的质押仓位（编号为用户的 next_position_id）
+    #[account(
+        init,
+        payer = authority,
+        space = StakePosition::LEN,
+        seeds = [b"stake_position", authority.key().as_ref(), user_instance.next_position_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub stake_position: Box<Account<'info, StakePosition>>,
+
     #[account(
         mut,
         constraint = user_lp_token_account.owner == authority.key() @ StakingError::InvalidLpTokenOwner,
@@ -41,6 +53,7 @@ pub struct EnterStaking<'info> {
         mut,
         constraint = gdtc_lp_in_account.owner == staking_instance.key() @ StakingError::InvalidVaultOwner,
         constraint = gdtc_lp_in_account.mint == staking_instance.staking_token_mint @ StakingError::InvalidLpTokenMint,
+        constraint = gdtc_lp_in_account.key() != staking_instance.lp_reward_vault @ StakingError::InvalidLpRewardVault,
     )]
     pub gdtc_lp_in_account: Account<'info, TokenAccount>,
 
@@ -49,6 +62,7 @@ pub struct EnterStaking<'info> {
     pub authority: Signer<'info>,
 
     pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
 }
 
 impl<'info> EnterStaking<'info> {
@@ -56,10 +70,10 @@ impl<'info> EnterStaking<'info> {
         &mut self,
         lp_staking_number: u64,
         stake_type: u64,
-        staked_info_index: u64,
     ) -> Result<()> {
         let staking_instance = &mut self.staking_instance;
         let user_instance = &mut self.user_instance;
+        let stake_position = &mut self.stake_position;
         let user_lp_token_account = &self.user_lp_token_account;
         let gdtc_lp_in_account = &self.gdtc_lp_in_account;
 
@@ -74,36 +88,17 @@ impl<'info> EnterStaking<'info> {
             return Err(StakingError::InvalidStakeAmount.into());
         }
 
-        if staked_info_index > 9 {
-            return Err(StakingError::InvalidStakedInfoIndex.into());
-        }
-        if stake_type > 2 {
-            return Err(StakingError::InvalidStakeType.into());
-        }
+        validate_stake_type(stake_type, staking_instance)?;
 
-        // 判断当前质押周期是否结束
+        // 判断当前是否在该池的质押窗口内
         let current_timestamp = clock.unix_timestamp as u64;
-        let is_end = match stake_type {
-            0 => current_timestamp > 2358810461,
-            1 => current_timestamp > 2350861661,
-            2 => current_timestamp > 2335136861,
-            _ => true,
-        };
-        if is_end {
+        if !staking_instance.pools[stake_type as usize].is_deposit_open(current_timestamp) {
             return Err(StakingError::StakingEnded.into());
         }
 
-        // 获取 staked_info
-        let index = staked_info_index as usize;
-        if user_instance.staked_info[index].is_staked {
-            return Err(StakingError::UserAlreadyStaked.into());
-        }
-        if stake_type >= staking_instance.pools.len() as u64 {
-            return Err(StakingError::InvalidStakeType.into());
-        }
-
         // 计算质押结束时间
-        let stake_end_time = generate_release_timestamps(current_timestamp, stake_type);
+        let duration_months = staking_instance.pools[stake_type as usize].duration_months;
+        let stake_end_time = generate_release_timestamps(current_timestamp, duration_months);
 
         // 更新用户状态
         user_instance.total_deposited_amount = user_instance
@@ -111,22 +106,33 @@ impl<'info> EnterStaking<'info> {
             .checked_add(lp_staking_number)
             .ok_or(StakingError::Overflow)?;
 
-        let staked_info = &mut user_instance.staked_info[index];
-        staked_info.deposited_amount = staked_info
-            .deposited_amount
-            .checked_add(lp_staking_number)
-            .ok_or(StakingError::Overflow)?;
-        staked_info.stake_type = stake_type;
-        staked_info.is_staked = true;
-        staked_info.stake_start_time = current_timestamp;
-        staked_info.stake_end_time = stake_end_time;
-
-
+        // 初始化质押仓位
+        stake_position.owner = self.authority.key();
+        stake_position.position_id = user_instance.next_position_id;
+        stake_position.staked = Staked {
+            deposited_amount: lp_staking_number,
+            reward_debt: 0,
+            accumulated_reward: 0,
+            is_staked: true,
+            stake_type,
+            stake_start_time: current_timestamp,
+            stake_end_time,
+            receivedReward: 0,
+            can_cancel_stake: false,
+        };
 
+        user_instance.next_position_id = user_instance
+            .next_position_id
+            .checked_add(1)
+            .ok_or(StakingError::Overflow)?;
+        user_instance.active_positions = user_instance
+            .active_positions
+            .checked_add(1)
+            .ok_or(StakingError::Overflow)?;
 
          // 更新奖励
         update_reward_pool(current_timestamp, staking_instance);
-        update_reward_debt(staking_instance, user_instance, staked_info_index);
+        update_reward_debt(staking_instance, &mut stake_position.staked);
 
         // 更新质押池状态
         let pool = &mut staking_instance.pools[stake_type as usize];
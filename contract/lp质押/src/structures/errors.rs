@@ -197,5 +197,29 @@ InvalidVaultOwner,
 #[msg("InvalidStakeAmount")]
 InvalidStakeAmount,
 
+#[msg("Cannot set yourself as superior")]
+SelfReferral,
+
+#[msg("Superior user account is missing or not initialized")]
+InvalidSuperiorAccount,
+
+#[msg("Invalid upline account in remaining accounts")]
+InvalidReferralAccount,
+
+#[msg("Early unstake is disabled")]
+EarlyUnstakeDisabled,
+
+#[msg("Staking has matured, use cancel_staking")]
+StakingAlreadyMatured,
+
+#[msg("User account uses the legacy layout, call migrate_user first")]
+UserNotMigrated,
+
+#[msg("LP reward vault has not been created, call init_lp_reward_vault first")]
+LpRewardVaultNotInitialized,
+
+#[msg("Account is not the LP reward vault recorded in the staking instance")]
+InvalidLpRewardVault,
+
 
 }
\ No newline at end of file
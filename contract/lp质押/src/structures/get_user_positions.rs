@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+
+use super::errors::StakingError;
+
+use super::*;
+
+/// 查询用户的质押仓位（只读）。
+/// remaining_accounts 传入用户的仓位PDA（种子 ["stake_position", 用户地址, 仓位编号]），
+/// 通常为编号 0..next_position_id，已解除（已关闭）的仓位会被跳过
+#[derive(Accounts)]
+pub struct GetUserPositions<'info> {
+    /// 用户信息
+    #[account(
+        seeds = [b"user", user_instance.user_address.as_ref()],
+        constraint = user_instance.to_account_info().data_len() == User::LEN @ StakingError::UserNotMigrated,
+        constraint = user_instance.isinit @ StakingError::UserNotInitialized,
+        bump
+    )]
+    pub user_instance: Box<Account<'info, User>>,
+}
+
+/// 仓位查询结果
+#[derive(Debug, Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct PositionInfo {
+    pub position_id: u64,
+    pub staked: Staked,
+}
+
+impl<'info> GetUserPositions<'info> {
+    pub fn process(&self, remaining_accounts: &'info [AccountInfo<'info>]) -> Result<Vec<PositionInfo>> {
+        let owner = self.user_instance.user_address;
+        let mut positions = Vec::new();
+
+        for position_info in remaining_accounts.iter() {
+            // 已关闭的仓位账户没有数据，跳过
+            if position_info.data_is_empty() {
+                continue;
+            }
+            let position = Account::<StakePosition>::try_from(position_info)?;
+            require_keys_eq!(position.owner, owner, StakingError::UserAccountIsNotMatch);
+
+            let (expected_key, _) = Pubkey::find_program_address(
+                &[b"stake_position", owner.as_ref(), position.position_id.to_le_bytes().as_ref()],
+                &crate::ID,
+            );
+            require_keys_eq!(position_info.key(), expected_key, StakingError::PdaAccountIsNotMatch);
+
+            positions.push(PositionInfo {
+                position_id: position.position_id,
+                staked: position.staked.clone(),
+            });
+        }
+
+        Ok(positions)
+    }
+}
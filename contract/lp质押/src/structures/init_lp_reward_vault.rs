@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+use super::errors::StakingError;
+use super::*;
+
+#[derive(Accounts)]
+pub struct InitLpRewardVault<'info> {
+    /// 全局质押状态
+    #[account(
+        mut,
+        seeds = [crate::constants::STAKING_SEED],
+        bump,
+        constraint = staking_instance.is_initialized @ StakingError::StakingInstanceNotInitialized,
+        constraint = staking_instance.authority == authority.key() @ StakingError::Unauthorized
+    )]
+    pub staking_instance: Account<'info, StakingInstance>,
+
+    /// LP 奖励金库（PDA，归质押实例PDA所有），接收提前解押未销毁的罚金
+    #[account(
+        init,
+        payer = authority,
+        token::mint = staking_token_mint,
+        token::authority = staking_instance,
+        seeds = [crate::constants::LP_REWARD_VAULT_SEED],
+        bump
+    )]
+    pub lp_reward_vault: Account<'info, TokenAccount>,
+
+    /// LP 代币 mint
+    #[account(
+        constraint = staking_token_mint.key() == staking_instance.staking_token_mint @ StakingError::InvalidLpTokenMint
+    )]
+    pub staking_token_mint: Account<'info, Mint>,
+
+    /// 管理员
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> InitLpRewardVault<'info> {
+    pub fn process(&mut self) -> Result<()> {
+        let staking_instance = &mut self.staking_instance;
+        staking_instance.lp_reward_vault = self.lp_reward_vault.key();
+
+        msg!("LP reward vault created: {:?}", staking_instance.lp_reward_vault);
+        Ok(())
+    }
+}
@@ -12,7 +12,7 @@ pub struct InitializeStaking<'info> {
     #[account(
         init,
         payer = authority,
-        space = 8 + core::mem::size_of::<StakingInstance>() + 3 * (8 + core::mem::size_of::<StakingPool>()),
+        space = StakingInstance::LEN,
         constraint = !staking_instance.is_initialized @ StakingError::StakingInstanceAlreadyInitialized,
         seeds = [crate::constants::STAKING_SEED],
         bump
@@ -45,12 +45,23 @@ pub struct InitializeStaking<'info> {
 impl<'info> InitializeStaking<'info> {
     pub fn process(
         &mut self,
-        reward_per_sec_3_months: u64,
-        reward_per_sec_6_months: u64,
-        reward_per_sec_12_months: u64,
+        pools: Vec<PoolConfig>,
         start_reward_timestamp: u64,
         gdtc_pool_address: Pubkey,
+        reward_split: RewardSplit,
     ) -> Result<()> {
+        // 验证开始时间和代币 Mint
+        self.validate_initialization_parameters(start_reward_timestamp)?;
+
+        // 验证奖励分配比例
+        reward_split.validate()?;
+
+        // 验证质押池配置，可以为空，之后通过 add_pool 追加
+        require!(pools.len() <= MAX_STAKING_POOLS, StakingError::PoolIsFull);
+        for pool in pools.iter() {
+            pool.validate()?;
+        }
+
         let staking_instance = &mut self.staking_instance;
 
         // 设置基础字段
@@ -60,62 +71,38 @@ impl<'info> InitializeStaking<'info> {
         staking_instance.secend_reward_token_mint = self.gdtc_token_mint.key();
         staking_instance.is_initialized = true;
         staking_instance.gdtc_pool_address = gdtc_pool_address;
-
-        // 初始化 3 个质押池
-        staking_instance.pools = [
-            StakingPool {
-                stake_type: 0, // 3 个月
-                reward_token_per_sec: reward_per_sec_3_months,
-                accumulated_reward_per_share: 0,
-                last_reward_timestamp: start_reward_timestamp,
-                total_shares: 0,
-            },
-            StakingPool {
-                stake_type: 1, // 6 个月
-                reward_token_per_sec: reward_per_sec_6_months,
-                accumulated_reward_per_share: 0,
-                last_reward_timestamp: start_reward_timestamp,
-                total_shares: 0,
-            },
-            StakingPool {
-                stake_type: 2, // 12 个月
-                reward_token_per_sec: reward_per_sec_12_months,
-                accumulated_reward_per_share: 0,
-                last_reward_timestamp: start_reward_timestamp,
-                total_shares: 0,
-            },
-        ];
+        staking_instance.reward_split = reward_split;
+        // 默认不限制上级质押量，不满足条件的推荐奖励转入全网分红池，可通过 set_referral_config 调整
+        staking_instance.min_referral_stake = 0;
+        staking_instance.referral_fallback_address = gdtc_pool_address;
+        // 默认不开放提前解押，需管理员通过 set_early_unstake_config 配置罚金
+        staking_instance.early_unstake_penalty_bps = 0;
+        staking_instance.penalty_burn_bps = 0;
+        staking_instance.lp_reward_vault = Pubkey::default();
+
+        // 按传入顺序创建质押池，编号即下标
+        staking_instance.pools = pools
+            .iter()
+            .enumerate()
+            .map(|(i, pool)| {
+                StakingPool::new(
+                    i as u64,
+                    pool.duration_months,
+                    pool.reward_per_sec,
+                    start_reward_timestamp,
+                    pool.deposit_open_timestamp,
+                    pool.deposit_close_timestamp,
+                )
+            })
+            .collect();
         Ok(())
     }
 
     /// 验证初始化参数
-    fn validate_initialization_parameters(
-        &self,
-        reward_per_sec_3_months: u64,
-        reward_per_sec_6_months: u64,
-        reward_per_sec_12_months: u64,
-        start_reward_timestamp: u64,
-    ) -> Result<()> {
-        // 验证奖励率
-        require!(reward_per_sec_3_months > 0, StakingError::InvalidRewardRate);
-        require!(reward_per_sec_6_months > 0, StakingError::InvalidRewardRate);
-        require!(reward_per_sec_12_months > 0, StakingError::InvalidRewardRate);
-
+    fn validate_initialization_parameters(&self, start_reward_timestamp: u64) -> Result<()> {
         // 验证时间戳
         require!(start_reward_timestamp > 0, StakingError::InvalidTimeParameters);
 
-        // 验证奖励率合理性（6个月池奖励率应该高于3个月池）
-        require!(
-            reward_per_sec_6_months >= reward_per_sec_3_months,
-            StakingError::InvalidRewardRate
-        );
-
-        // 验证奖励率合理性（12个月池奖励率应该高于6个月池）
-        require!(
-            reward_per_sec_12_months >= reward_per_sec_6_months,
-            StakingError::InvalidRewardRate
-        );
-
         // 验证代币Mint
         require!(
             self.reward_token_mint.key() != self.staking_token_mint.key(),
@@ -132,4 +119,4 @@ impl<'info> InitializeStaking<'info> {
 
         Ok(())
     }
-}
\ No newline at end of file
+}
@@ -8,6 +8,7 @@ use super::errors::StakingError;
 use super::*;
 
 #[derive(Accounts)]
+#[instruction(user_superior_account: Pubkey)]
 pub struct InitializeUser<'info> {
     #[account(
         mut,
@@ -20,13 +21,21 @@ pub struct InitializeUser<'info> {
     #[account(
         init,
         payer = authority,
-        space = 8 + core::mem::size_of::<User>(),
+        space = User::LEN,
         seeds = [b"user", authority.key().as_ref()],
         constraint = !user_instance.isinit @ StakingError::UserAlreadyStaked,
         bump
     )]
     pub user_instance: Account<'info, User>,
 
+    /// 上级的用户账户，上级不为空时必须传入（上级需先完成初始化）
+    #[account(
+        seeds = [b"user", user_superior_account.as_ref()],
+        bump,
+        constraint = superior_instance.to_account_info().data_len() == User::LEN @ StakingError::UserNotMigrated,
+        constraint = superior_instance.isinit @ StakingError::InvalidSuperiorAccount
+    )]
+    pub superior_instance: Option<Account<'info, User>>,
 
     #[account(mut)]
     pub authority: Signer<'info>,
@@ -46,27 +55,28 @@ impl<'info> InitializeUser<'info> {
             StakingError::InvalidUserAddress
         );
 
+        // 不能把自己设为上级
+        require!(
+            user_superior_account != self.authority.key(),
+            StakingError::SelfReferral
+        );
+
+        // 上级必须是已初始化的用户，新用户只能挂在已有用户下，推荐关系不会形成环
+        if user_superior_account != Pubkey::default() {
+            require!(
+                self.superior_instance.is_some(),
+                StakingError::InvalidSuperiorAccount
+            );
+        }
+
 
         // 设置用户基本信息
         user_instance.user_address = self.authority.key();
         user_instance.total_deposited_amount = 0;
         user_instance.user_superior_account= user_superior_account;
         user_instance.isinit = true;
-        
-    
-
-        // 初始化 10 个质押槽位
-        for staked in user_instance.staked_info.iter_mut() {
-            staked.deposited_amount = 0;
-            staked.reward_debt = 0;
-            staked.accumulated_reward = 0;
-            staked.is_staked = false;
-            staked.stake_type = 0;
-            staked.stake_start_time = 0;
-            staked.stake_end_time = 0;
-            staked.receivedReward = 0;
-            staked.can_cancel_stake = false;
-        }
+        user_instance.next_position_id = 0;
+        user_instance.active_positions = 0;
 
         Ok(())
     }
@@ -0,0 +1,112 @@
+use anchor_lang::prelude::*;
+
+use super::errors::StakingError;
+use crate::structures::utils::validate_time_parameters;
+
+use super::*;
+
+/// 将旧版质押实例（固定 3 个池的数组）迁移为当前布局：
+/// 账户扩容到 StakingInstance::LEN，保留各池的奖励进度和份额，补充期限、质押窗口和奖励分配等新字段
+#[derive(Accounts)]
+pub struct MigrateStakingInstance<'info> {
+    /// CHECK: 旧版质押实例，布局与当前 StakingInstance 不兼容，在指令中手动校验和解析
+    #[account(
+        mut,
+        seeds = [crate::constants::STAKING_SEED],
+        bump,
+        owner = crate::ID @ StakingError::InvalidStakingInstance
+    )]
+    pub staking_instance: UncheckedAccount<'info>,
+
+    /// 管理员（支付扩容所需租金）
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> MigrateStakingInstance<'info> {
+    pub fn process(
+        &mut self,
+        deposit_open_timestamp: u64,
+        deposit_close_3_months: u64,
+        deposit_close_6_months: u64,
+        deposit_close_12_months: u64,
+        reward_split: RewardSplit,
+    ) -> Result<()> {
+        let instance_info = self.staking_instance.to_account_info();
+
+        // 读取旧版布局，已是当前长度的账户视为已迁移
+        let legacy = {
+            let data = instance_info.try_borrow_data()?;
+            require!(data.len() < StakingInstance::LEN, StakingError::StakingInstanceAlreadyInitialized);
+            require!(data[..8] == *StakingInstance::DISCRIMINATOR, StakingError::InvalidStakingInstance);
+            LegacyStakingInstance::deserialize(&mut &data[8..])?
+        };
+        require!(legacy.is_initialized, StakingError::StakingInstanceNotInitialized);
+        require_keys_eq!(legacy.authority, self.authority.key(), StakingError::Unauthorized);
+
+        reward_split.validate()?;
+        validate_time_parameters(deposit_open_timestamp, deposit_close_3_months)?;
+        validate_time_parameters(deposit_open_timestamp, deposit_close_6_months)?;
+        validate_time_parameters(deposit_open_timestamp, deposit_close_12_months)?;
+
+        // 旧版 3 个池依次对应 3、6、12 个月，保留奖励速率、累计奖励分摊和份额
+        let durations = [3u64, 6, 12];
+        let deposit_closes = [deposit_close_3_months, deposit_close_6_months, deposit_close_12_months];
+        let pools = legacy
+            .pools
+            .iter()
+            .enumerate()
+            .map(|(i, pool)| StakingPool {
+                stake_type: i as u64,
+                duration_months: durations[i],
+                reward_token_per_sec: pool.reward_token_per_sec,
+                accumulated_reward_per_share: pool.accumulated_reward_per_share,
+                last_reward_timestamp: pool.last_reward_timestamp,
+                total_shares: pool.total_shares,
+                deposit_open_timestamp,
+                deposit_close_timestamp: deposit_closes[i],
+            })
+            .collect();
+
+        let staking_instance = StakingInstance {
+            authority: legacy.authority,
+            is_initialized: legacy.is_initialized,
+            reward_token_mint: legacy.reward_token_mint,
+            staking_token_mint: legacy.staking_token_mint,
+            secend_reward_token_mint: legacy.secend_reward_token_mint,
+            pools,
+            gdtc_pool_address: legacy.gdtc_pool_address,
+            reward_split,
+            min_referral_stake: 0,
+            referral_fallback_address: legacy.gdtc_pool_address,
+            early_unstake_penalty_bps: 0,
+            penalty_burn_bps: 0,
+            lp_reward_vault: Pubkey::default(),
+        };
+
+        // 补足扩容后的租金再扩容
+        let rent_lamports = Rent::get()?.minimum_balance(StakingInstance::LEN);
+        let top_up = rent_lamports.saturating_sub(instance_info.lamports());
+        if top_up > 0 {
+            anchor_lang::system_program::transfer(
+                CpiContext::new(
+                    self.system_program.to_account_info(),
+                    anchor_lang::system_program::Transfer {
+                        from: self.authority.to_account_info(),
+                        to: instance_info.clone(),
+                    },
+                ),
+                top_up,
+            )?;
+        }
+        instance_info.resize(StakingInstance::LEN)?;
+
+        let mut data = instance_info.try_borrow_mut_data()?;
+        staking_instance.try_serialize(&mut &mut data[..])?;
+
+        msg!("Staking instance migrated to {} bytes", StakingInstance::LEN);
+        Ok(())
+    }
+}
@@ -0,0 +1,101 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::LEGACY_STAKED_SLOTS;
+use super::errors::StakingError;
+use crate::structures::utils::init_stake_position;
+
+use super::*;
+
+/// 将旧版用户账户（质押信息内嵌在 staked_info 中）迁移为新布局，
+/// 旧版中每个已质押的槽位转成一个 StakePosition PDA，仓位编号等于槽位下标。
+/// remaining_accounts 按槽位顺序传入各已质押槽位对应的仓位PDA（未质押的槽位不传）
+#[derive(Accounts)]
+pub struct MigrateUser<'info> {
+    /// 用户签名者（支付新仓位账户租金）
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// CHECK: 旧版用户账户，布局与当前 User 不兼容，在指令中手动校验和解析
+    #[account(
+        mut,
+        seeds = [b"user", authority.key().as_ref()],
+        bump,
+        owner = crate::ID @ StakingError::InvalidUserInstance
+    )]
+    pub user_instance: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> MigrateUser<'info> {
+    pub fn process(&mut self, remaining_accounts: &'info [AccountInfo<'info>]) -> Result<()> {
+        let authority = self.authority.key();
+        let user_info = self.user_instance.to_account_info();
+
+        // 读取旧版布局，新布局账户长度不足，视为已迁移
+        let legacy = {
+            let data = user_info.try_borrow_data()?;
+            require!(data.len() >= LegacyUser::LEN, StakingError::UserAlreadyInitialized);
+            require!(data[..8] == *User::DISCRIMINATOR, StakingError::InvalidUserInstance);
+            LegacyUser::deserialize(&mut &data[8..])?
+        };
+        require!(
+            legacy.isinit && legacy.user_address == authority,
+            StakingError::UserAccountIsNotMatch
+        );
+
+        // 逐个已质押的槽位创建仓位账户
+        let mut position_accounts = remaining_accounts.iter();
+        let mut active_positions: u64 = 0;
+        for (slot, staked) in legacy.staked_info.iter().enumerate() {
+            if !staked.is_staked {
+                continue;
+            }
+            let position_id = slot as u64;
+            let position_info = position_accounts
+                .next()
+                .ok_or(StakingError::PdaAccountIsNotMatch)?;
+
+            let position = StakePosition {
+                owner: authority,
+                position_id,
+                staked: staked.clone(),
+            };
+            init_stake_position(
+                &self.authority.to_account_info(),
+                position_info,
+                &self.system_program.to_account_info(),
+                &position,
+            )?;
+
+            active_positions += 1;
+        }
+
+        // 写入新布局，旧槽位编号保留给迁移的仓位，新仓位从 LEGACY_STAKED_SLOTS 开始
+        let user = User {
+            total_deposited_amount: legacy.total_deposited_amount,
+            user_superior_account: legacy.user_superior_account,
+            isinit: legacy.isinit,
+            user_address: legacy.user_address,
+            next_position_id: LEGACY_STAKED_SLOTS as u64,
+            active_positions,
+        };
+        user_info.resize(User::LEN)?;
+        {
+            let mut data = user_info.try_borrow_mut_data()?;
+            user.try_serialize(&mut &mut data[..])?;
+        }
+
+        // 账户缩小后多出的租金退还给用户
+        let rent_lamports = Rent::get()?.minimum_balance(User::LEN);
+        let surplus = user_info.lamports().saturating_sub(rent_lamports);
+        if surplus > 0 {
+            let authority_info = self.authority.to_account_info();
+            **user_info.try_borrow_mut_lamports()? -= surplus;
+            **authority_info.try_borrow_mut_lamports()? += surplus;
+        }
+
+        msg!("User {:?} migrated, {} stake positions created", authority, active_positions);
+        Ok(())
+    }
+}
@@ -4,6 +4,17 @@ pub mod claim_rewards;
 pub mod enter_staking;
 pub mod initialize_staking;
 pub mod initialize_user;
+pub mod add_pool;
+pub mod set_pool_deposit_window;
+pub mod set_reward_split;
+pub mod set_referral_config;
+pub mod early_unstake;
+pub mod migrate_user;
+pub mod get_user_positions;
+pub mod migrate_staking_instance;
+pub mod set_early_unstake_config;
+pub mod init_lp_reward_vault;
+pub mod withdraw_lp_reward_vault;
 pub mod errors;
 pub mod utils;
 pub mod  tools;
@@ -16,18 +27,195 @@ pub struct StakingInstance {
     pub reward_token_mint: Pubkey,  // 奖励代币 Mint 地址
     pub staking_token_mint: Pubkey, // 质押代币 Mint 地址
     pub secend_reward_token_mint: Pubkey, // 第二个奖励代币（如 GDTC）
-    pub pools: [StakingPool; 3],    // 固定3个质押池
+    pub pools: Vec<StakingPool>,    // 质押池（管理员配置，最多 MAX_STAKING_POOLS 个）
     //全网分红池
     pub gdtc_pool_address: Pubkey,
+    pub reward_split: RewardSplit,  // 奖励分配比例
+    pub min_referral_stake: u64,    // 上级领取推荐奖励所需的最低质押量（total_deposited_amount）
+    pub referral_fallback_address: Pubkey, // 上级不满足条件时推荐奖励的接收地址
+    pub early_unstake_penalty_bps: u16, // 提前解押的最高罚金比例（质押开始时），随时间线性递减到 0，为 0 时不允许提前解押
+    pub penalty_burn_bps: u16,     // 罚金中销毁的比例，其余转入 LP 奖励金库
+    pub lp_reward_vault: Pubkey,   // LP 奖励金库地址（由 init_lp_reward_vault 创建，未创建时为默认地址）
+}
+
+impl StakingInstance {
+    pub const LEN: usize = 8 // discriminator
+        + 32 // authority
+        + 1 // is_initialized
+        + 32 // reward_token_mint
+        + 32 // staking_token_mint
+        + 32 // secend_reward_token_mint
+        + 4 + crate::constants::MAX_STAKING_POOLS * StakingPool::LEN // pools
+        + 32 // gdtc_pool_address
+        + RewardSplit::LEN // reward_split
+        + 8 // min_referral_stake
+        + 32 // referral_fallback_address
+        + 2 // early_unstake_penalty_bps
+        + 2 // penalty_burn_bps
+        + 32; // lp_reward_vault
+}
+
+/// 奖励分配比例（基点），各级推荐奖励与其余三项之和必须等于 10000
+#[derive(Debug, Clone, Copy, AnchorSerialize, AnchorDeserialize)]
+pub struct RewardSplit {
+    pub referral_level_bps: [u16; crate::constants::MAX_REFERRAL_LEVELS], // 各级上级推荐奖励，下标 0 为直接上级，0 表示该级不发放
+    pub global_pool_bps: u16, // 全网分红池
+    pub burn_bps: u16,        // 黑洞销毁
+    pub user_bps: u16,        // 用户
+}
+
+/// 按 RewardSplit 拆分后的奖励数量
+#[derive(Debug, Clone, Copy)]
+pub struct RewardShares {
+    pub referral: [u64; crate::constants::MAX_REFERRAL_LEVELS],
+    pub global_pool: u64,
+    pub burn: u64,
+    pub user: u64,
+}
+
+impl RewardSplit {
+    pub const LEN: usize = 2 * crate::constants::MAX_REFERRAL_LEVELS + 2 * 3;
+
+    /// 推荐奖励各级比例之和
+    pub fn referral_bps(&self) -> u64 {
+        self.referral_level_bps.iter().map(|bps| *bps as u64).sum()
+    }
+
+    /// 需要发放推荐奖励的层级数：最后一个比例不为 0 的层级之后的各级都不发放，无需遍历
+    pub fn active_referral_levels(&self) -> usize {
+        self.referral_level_bps
+            .iter()
+            .rposition(|bps| *bps > 0)
+            .map_or(0, |level| level + 1)
+    }
+
+    /// 校验各项比例之和等于 10000
+    pub fn validate(&self) -> Result<()> {
+        let total = self.referral_bps()
+            + self.global_pool_bps as u64
+            + self.burn_bps as u64
+            + self.user_bps as u64;
+        require!(
+            total == crate::constants::BPS_DENOMINATOR,
+            errors::StakingError::InvalidConfiguration
+        );
+        Ok(())
+    }
+
+    /// 拆分奖励：各项（含用户）都按各自比例向下取整，
+    /// 取整余数（最多 MAX_REFERRAL_LEVELS + 2 个最小单位）计入全网分红池，保证各部分之和等于 amount
+    pub fn split(&self, amount: u64) -> Result<RewardShares> {
+        let share = |bps: u16| -> Result<u64> {
+            Ok((amount as u128)
+                .checked_mul(bps as u128)
+                .and_then(|v| v.checked_div(crate::constants::BPS_DENOMINATOR as u128))
+                .ok_or(errors::StakingError::Overflow)? as u64)
+        };
+        let mut referral = [0u64; crate::constants::MAX_REFERRAL_LEVELS];
+        for (level, bps) in self.referral_level_bps.iter().enumerate() {
+            referral[level] = share(*bps)?;
+        }
+        let global_pool = share(self.global_pool_bps)?;
+        let burn = share(self.burn_bps)?;
+        let user = share(self.user_bps)?;
+
+        // 比例之和为 10000，各项向下取整后之和不会超过 amount
+        let dust = amount
+            .checked_sub(referral.iter().sum())
+            .and_then(|v| v.checked_sub(global_pool))
+            .and_then(|v| v.checked_sub(burn))
+            .and_then(|v| v.checked_sub(user))
+            .ok_or(errors::StakingError::Underflow)?;
+        let global_pool = global_pool
+            .checked_add(dust)
+            .ok_or(errors::StakingError::Overflow)?;
+        Ok(RewardShares { referral, global_pool, burn, user })
+    }
+}
+
+/// 旧版 StakingInstance 账户布局（固定 3 个池），仅用于 migrate_staking_instance 读取
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct LegacyStakingInstance {
+    pub authority: Pubkey,
+    pub is_initialized: bool,
+    pub reward_token_mint: Pubkey,
+    pub staking_token_mint: Pubkey,
+    pub secend_reward_token_mint: Pubkey,
+    pub pools: [LegacyStakingPool; 3],
+    pub gdtc_pool_address: Pubkey,
+}
+
+/// 旧版质押池布局（0 代表3个月，1 代表6个月，2 代表12个月）
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct LegacyStakingPool {
+    pub stake_type: u64,
+    pub reward_token_per_sec: u64,
+    pub accumulated_reward_per_share: u64,
+    pub last_reward_timestamp: u64,
+    pub total_shares: u64,
+}
+
+/// 质押池配置（initialize_staking 参数）
+#[derive(Debug, Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct PoolConfig {
+    pub duration_months: u64,         // 质押期限（月）
+    pub reward_per_sec: u64,          // 每秒奖励代币数量
+    pub deposit_open_timestamp: u64,  // 开放质押时间
+    pub deposit_close_timestamp: u64, // 停止质押时间
+}
+
+impl PoolConfig {
+    /// 校验期限在 1..=MAX_POOL_DURATION_MONTHS 内、奖励速率大于 0、质押窗口有效
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            self.duration_months > 0
+                && self.duration_months <= crate::constants::MAX_POOL_DURATION_MONTHS,
+            errors::StakingError::InvalidStakeDuration
+        );
+        require!(self.reward_per_sec > 0, errors::StakingError::InvalidRewardRate);
+        utils::validate_time_parameters(self.deposit_open_timestamp, self.deposit_close_timestamp)
+    }
 }
 
 #[derive(Debug, Clone, AnchorSerialize, AnchorDeserialize)]
 pub struct StakingPool {
-    pub stake_type: u64, // 0 代表3个月，1 代表6个月，2代表12个月
+    pub stake_type: u64, // 质押池编号，即在 pools 中的下标
+    pub duration_months: u64, // 质押期限（月）
     pub reward_token_per_sec: u64, // 每秒奖励代币数量
     pub accumulated_reward_per_share: u64, // 累计奖励分摊
     pub last_reward_timestamp: u64, // 上次更新奖励的时间戳
     pub total_shares: u64, // 该池中质押的总份额
+    pub deposit_open_timestamp: u64, // 开放质押时间
+    pub deposit_close_timestamp: u64, // 停止质押时间
+}
+
+impl StakingPool {
+    pub const LEN: usize = 8 * 8;
+
+    pub fn new(
+        stake_type: u64,
+        duration_months: u64,
+        reward_token_per_sec: u64,
+        last_reward_timestamp: u64,
+        deposit_open_timestamp: u64,
+        deposit_close_timestamp: u64,
+    ) -> Self {
+        StakingPool {
+            stake_type,
+            duration_months,
+            reward_token_per_sec,
+            accumulated_reward_per_share: 0,
+            last_reward_timestamp,
+            total_shares: 0,
+            deposit_open_timestamp,
+            deposit_close_timestamp,
+        }
+    }
+
+    /// 当前时间是否在质押窗口内
+    pub fn is_deposit_open(&self, current_timestamp: u64) -> bool {
+        current_timestamp >= self.deposit_open_timestamp && current_timestamp <= self.deposit_close_timestamp
+    }
 }
 
 #[account]
@@ -35,9 +223,54 @@ pub struct User {
     //这个字段必须第一位
     pub total_deposited_amount: u64, // 用户总存入的质押金额
     pub user_superior_account: Pubkey, // 用户的上级 Token 账户
-    pub staked_info: [Staked; 10],   // 固定10个质押池
     pub isinit: bool,
     pub user_address: Pubkey,
+    pub next_position_id: u64,       // 下一个质押仓位编号（已创建的仓位编号为 0..next_position_id）
+    pub active_positions: u64,       // 当前未解除的质押仓位数量
+}
+
+impl User {
+    pub const LEN: usize = 8 // discriminator
+        + 8 // total_deposited_amount
+        + 32 // user_superior_account
+        + 1 // isinit
+        + 32 // user_address
+        + 8 // next_position_id
+        + 8; // active_positions
+}
+
+/// 旧版 User 账户布局（质押信息内嵌在用户账户中），仅用于 migrate_user 读取
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct LegacyUser {
+    pub total_deposited_amount: u64,
+    pub user_superior_account: Pubkey,
+    pub staked_info: [Staked; crate::constants::LEGACY_STAKED_SLOTS],
+    pub isinit: bool,
+    pub user_address: Pubkey,
+}
+
+impl LegacyUser {
+    pub const LEN: usize = 8 // discriminator
+        + 8 // total_deposited_amount
+        + 32 // user_superior_account
+        + crate::constants::LEGACY_STAKED_SLOTS * Staked::LEN // staked_info
+        + 1 // isinit
+        + 32; // user_address
+}
+
+/// 质押仓位，每笔质押一个 PDA 账户，种子为 ["stake_position", 用户地址, 仓位编号]
+#[account]
+pub struct StakePosition {
+    pub owner: Pubkey,     // 用户地址（放在第一位，便于按用户筛选仓位）
+    pub position_id: u64,  // 仓位编号
+    pub staked: Staked,    // 质押信息
+}
+
+impl StakePosition {
+    pub const LEN: usize = 8 // discriminator
+        + 32 // owner
+        + 8 // position_id
+        + Staked::LEN; // staked
 }
 
 #[derive(Debug, Clone, AnchorSerialize, AnchorDeserialize)]
@@ -52,3 +285,64 @@ pub struct Staked {
     pub receivedReward: u64,     //已领取收益
     pub can_cancel_stake: bool,  //是否可以解除质押
 }
+
+impl Staked {
+    pub const LEN: usize = 8 * 7 + 2; // 7 个 u64 + 2 个 bool
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn split_with_referral(referral_level_bps: [u16; crate::constants::MAX_REFERRAL_LEVELS]) -> RewardSplit {
+        let referral: u16 = referral_level_bps.iter().sum();
+        RewardSplit {
+            referral_level_bps,
+            global_pool_bps: 1000,
+            burn_bps: 1000,
+            user_bps: 8000 - referral,
+        }
+    }
+
+    #[test]
+    fn active_referral_levels_stops_after_last_nonzero_level() {
+        assert_eq!(split_with_referral([500, 300, 0, 0, 0]).active_referral_levels(), 2);
+        assert_eq!(split_with_referral([500, 0, 200, 0, 0]).active_referral_levels(), 3);
+        assert_eq!(split_with_referral([100, 100, 100, 100, 100]).active_referral_levels(), 5);
+    }
+
+    #[test]
+    fn split_parts_sum_to_amount_for_odd_values() {
+        let split = RewardSplit {
+            referral_level_bps: [700, 300, 300, 100, 100],
+            global_pool_bps: 1100,
+            burn_bps: 300,
+            user_bps: 7100,
+        };
+        for amount in [1u64, 7, 99, 12_345, 1_000_001, u64::MAX / 3] {
+            let shares = split.split(amount).unwrap();
+            let total = shares.referral.iter().map(|v| *v as u128).sum::<u128>()
+                + shares.global_pool as u128
+                + shares.burn as u128
+                + shares.user as u128;
+            assert_eq!(total, amount as u128);
+            // 用户按比例向下取整，余数计入全网分红池
+            assert_eq!(shares.user as u128, amount as u128 * 7100 / 10_000);
+        }
+    }
+
+    #[test]
+    fn split_dust_goes_to_global_pool() {
+        let shares = split_with_referral([500, 300, 0, 0, 0]).split(7).unwrap();
+
+        assert_eq!(shares.referral, [0; crate::constants::MAX_REFERRAL_LEVELS]);
+        assert_eq!(shares.burn, 0);
+        assert_eq!(shares.user, 5);
+        assert_eq!(shares.global_pool, 2);
+    }
+
+    #[test]
+    fn active_referral_levels_is_zero_without_referral() {
+        assert_eq!(split_with_referral([0; crate::constants::MAX_REFERRAL_LEVELS]).active_referral_levels(), 0);
+    }
+}
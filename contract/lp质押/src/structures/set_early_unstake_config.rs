@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::BPS_DENOMINATOR;
+use super::errors::StakingError;
+use super::*;
+
+#[derive(Accounts)]
+pub struct SetEarlyUnstakeConfig<'info> {
+    /// 全局质押状态
+    #[account(
+        mut,
+        seeds = [crate::constants::STAKING_SEED],
+        bump,
+        constraint = staking_instance.is_initialized @ StakingError::StakingInstanceNotInitialized,
+        constraint = staking_instance.authority == authority.key() @ StakingError::Unauthorized
+    )]
+    pub staking_instance: Account<'info, StakingInstance>,
+
+    /// 管理员
+    pub authority: Signer<'info>,
+}
+
+impl<'info> SetEarlyUnstakeConfig<'info> {
+    pub fn process(
+        &mut self,
+        early_unstake_penalty_bps: u16,
+        penalty_burn_bps: u16,
+    ) -> Result<()> {
+        // 比例不能超过 100%
+        require!(
+            early_unstake_penalty_bps as u64 <= BPS_DENOMINATOR
+                && penalty_burn_bps as u64 <= BPS_DENOMINATOR,
+            StakingError::InvalidConfiguration
+        );
+
+        // 有未销毁的罚金时必须先创建 LP 奖励金库
+        if early_unstake_penalty_bps > 0 && (penalty_burn_bps as u64) < BPS_DENOMINATOR {
+            require!(
+                self.staking_instance.lp_reward_vault != Pubkey::default(),
+                StakingError::LpRewardVaultNotInitialized
+            );
+        }
+
+        let staking_instance = &mut self.staking_instance;
+        staking_instance.early_unstake_penalty_bps = early_unstake_penalty_bps;
+        staking_instance.penalty_burn_bps = penalty_burn_bps;
+
+        msg!(
+            "Early unstake config set: max penalty {} bps, burn {} bps",
+            early_unstake_penalty_bps,
+            penalty_burn_bps
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+
+use super::errors::StakingError;
+use super::*;
+use crate::structures::utils::{validate_stake_type, validate_time_parameters};
+
+#[derive(Accounts)]
+pub struct SetPoolDepositWindow<'info> {
+    /// 全局质押状态
+    #[account(
+        mut,
+        seeds = [crate::constants::STAKING_SEED],
+        bump,
+        constraint = staking_instance.is_initialized @ StakingError::StakingInstanceNotInitialized,
+        constraint = staking_instance.authority == authority.key() @ StakingError::Unauthorized
+    )]
+    pub staking_instance: Account<'info, StakingInstance>,
+
+    /// 管理员
+    pub authority: Signer<'info>,
+}
+
+impl<'info> SetPoolDepositWindow<'info> {
+    pub fn process(
+        &mut self,
+        stake_type: u64,
+        deposit_open_timestamp: u64,
+        deposit_close_timestamp: u64,
+    ) -> Result<()> {
+        let staking_instance = &mut self.staking_instance;
+
+        validate_stake_type(stake_type, staking_instance)?;
+        validate_time_parameters(deposit_open_timestamp, deposit_close_timestamp)?;
+
+        // 仅影响之后的质押，已有质押的到期时间不变
+        let pool = &mut staking_instance.pools[stake_type as usize];
+        pool.deposit_open_timestamp = deposit_open_timestamp;
+        pool.deposit_close_timestamp = deposit_close_timestamp;
+
+        msg!(
+            "Pool {} deposit window set: {} - {}",
+            stake_type,
+            deposit_open_timestamp,
+            deposit_close_timestamp
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+
+use super::errors::StakingError;
+use super::*;
+
+#[derive(Accounts)]
+pub struct SetReferralConfig<'info> {
+    /// 全局质押状态
+    #[account(
+        mut,
+        seeds = [crate::constants::STAKING_SEED],
+        bump,
+        constraint = staking_instance.is_initialized @ StakingError::StakingInstanceNotInitialized,
+        constraint = staking_instance.authority == authority.key() @ StakingError::Unauthorized
+    )]
+    pub staking_instance: Account<'info, StakingInstance>,
+
+    /// 管理员
+    pub authority: Signer<'info>,
+}
+
+impl<'info> SetReferralConfig<'info> {
+    pub fn process(&mut self, min_referral_stake: u64, referral_fallback_address: Pubkey) -> Result<()> {
+        // 备用地址不能为空，否则不满足条件的推荐奖励无法发放
+        require!(
+            referral_fallback_address != Pubkey::default(),
+            StakingError::InvalidConfiguration
+        );
+
+        let staking_instance = &mut self.staking_instance;
+        staking_instance.min_referral_stake = min_referral_stake;
+        staking_instance.referral_fallback_address = referral_fallback_address;
+
+        msg!(
+            "Referral config set: min stake {}, fallback {:?}",
+            min_referral_stake,
+            referral_fallback_address
+        );
+        Ok(())
+    }
+}
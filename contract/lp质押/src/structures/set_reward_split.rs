@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+
+use super::errors::StakingError;
+use super::*;
+
+#[derive(Accounts)]
+pub struct SetRewardSplit<'info> {
+    /// 全局质押状态
+    #[account(
+        mut,
+        seeds = [crate::constants::STAKING_SEED],
+        bump,
+        constraint = staking_instance.is_initialized @ StakingError::StakingInstanceNotInitialized,
+        constraint = staking_instance.authority == authority.key() @ StakingError::Unauthorized
+    )]
+    pub staking_instance: Account<'info, StakingInstance>,
+
+    /// 管理员
+    pub authority: Signer<'info>,
+}
+
+impl<'info> SetRewardSplit<'info> {
+    pub fn process(&mut self, reward_split: RewardSplit) -> Result<()> {
+        // 各项比例之和必须等于 10000
+        reward_split.validate()?;
+
+        self.staking_instance.reward_split = reward_split;
+
+        msg!(
+            "Reward split set: referral {:?} / global pool {} / burn {} / user {} bps",
+            reward_split.referral_level_bps,
+            reward_split.global_pool_bps,
+            reward_split.burn_bps,
+            reward_split.user_bps
+        );
+        Ok(())
+    }
+}
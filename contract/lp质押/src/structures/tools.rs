@@ -90,18 +90,11 @@ fn date_to_timestamp(year: u64, month: u64, day: u64) -> u64 {
     timestamp
 }
 
-/// 根据质押类型，计算出时间戳，stake_type 为 0 代表三个月，1 代表六个月，2 代表十二个月，
-pub fn generate_release_timestamps(purchase_timestamp: u64, stake_type: u64) -> u64 {
-    let (mut year, mut month, mut day) = timestamp_to_ymd(purchase_timestamp);
+/// 根据质押池期限（月），计算出到期时间戳
+pub fn generate_release_timestamps(purchase_timestamp: u64, months_to_add: u64) -> u64 {
+    let (mut year, mut month, day) = timestamp_to_ymd(purchase_timestamp);
     let addtime = date_to_timestamp(year, month, day);
     let add = purchase_timestamp - addtime;
-    // 根据质押类型设置需要加的月份数
-    let months_to_add = match stake_type {
-        0 => 3,        // 三个月
-        1 => 6,        // 六个月
-        2 => 12,       // 十二个月
-        _ => return 0, // 无效的 stake_type，返回 0
-    };
 
     // 计算新日期
     for _ in 0..months_to_add {
@@ -11,22 +11,11 @@ use super::errors::StakingError;
 use super::*;
 
 // 工具函数
-pub fn calculate_stake_duration(stake_type: u64) -> Result<u64> {
-    match stake_type {
-        0 => Ok(90 * 24 * 60 * 60), // 3个月
-        1 => Ok(180 * 24 * 60 * 60), // 6个月
-        2 => Ok(365 * 24 * 60 * 60), // 12个月
-        _ => Err(StakingError::InvalidStakeType.into()),
-    }
-}
-
-pub fn validate_stake_type(stake_type: u64) -> Result<()> {
-    require!(stake_type < 3, StakingError::InvalidStakeType);
-    Ok(())
-}
-
-pub fn validate_staked_info_index(index: u64) -> Result<()> {
-    require!(index < 10, StakingError::InvalidStakedInfoIndex);
+pub fn validate_stake_type(stake_type: u64, staking_instance: &StakingInstance) -> Result<()> {
+    require!(
+        (stake_type as usize) < staking_instance.pools.len(),
+        StakingError::InvalidStakeType
+    );
     Ok(())
 }
 
@@ -87,12 +76,8 @@ pub fn update_reward_pool(current_timestamp: u64, staking_instance: &mut Staking
 
 pub fn store_pending_reward(
     staking_instance: &mut StakingInstance,
-    user_instance: &mut User,
-    staked_info_number: u64, // 修改为索引
+    staked_info: &mut Staked, // 用户质押仓位的质押信息
 ) -> Result<()> {
-    // 获取用户对应的质押信息
-    let staked_info = &mut user_instance.staked_info[staked_info_number as usize];
-
     // 确保该质押池已被质押
     if !staked_info.is_staked {
         return Ok(()); // 如果该质押池没有质押，直接返回
@@ -101,11 +86,6 @@ pub fn store_pending_reward(
     // 获取质押类型对应的池子
     let stake_type = staked_info.stake_type as usize;
 
-    // 检查 stake_type 是否为有效池子索引
-    // if stake_type >= staking_instance.pools.len() {
-    //     return Err(ErrorCode::InvalidStakeType.into()); // 自定义错误类型
-    // }
-
     // 获取对应池子
     let pool = &staking_instance.pools[stake_type];
 
@@ -136,12 +116,8 @@ pub fn store_pending_reward(
 
 pub fn update_reward_debt(
     staking_instance: &mut StakingInstance,
-    user_instance: &mut User,
-    staked_info_number: u64, // 用户质押池的索引
+    staked_info: &mut Staked, // 用户质押仓位的质押信息
 ) {
-    // 获取用户对应的质押信息
-    let staked_info = &mut user_instance.staked_info[staked_info_number as usize];
-
     // 确保该质押池已被质押
     if !staked_info.is_staked {
         return; // 如果该质押池没有质押，直接返回
@@ -156,19 +132,6 @@ pub fn update_reward_debt(
     // 获取对应池子
     let pool = &staking_instance.pools[stake_type];
 
-    // msg!(
-    //     "Hello world!!",
-    //     pool.accumulated_reward_per_share,
-    //     staked_info.deposited_amount
-    // );
-    // 更新该质押池的 reward_debt
-    // msg!(
-    //     "staked_info
-    //     .deposited_amount",
-    //     staked_info.deposited_amount
-    // );
-    // msg!("accumulated_reward_per_share", accumulated_reward_per_share);
-
     staked_info.reward_debt = (staked_info.deposited_amount as u128)
         .checked_mul(pool.accumulated_reward_per_share as u128)
         .and_then(|v| v.checked_div(crate::constants::COMPUTATION_DECIMALS as u128))
@@ -182,8 +145,168 @@ pub fn can_unstake(staked: &Staked, current_timestamp: u64) -> bool {
     staked.is_staked && staked.stake_end_time <= current_timestamp
 }
 
-pub fn calculate_referral_reward(user: &User, amount: u64) -> u64 {
-    // 计算推荐奖励，假设为10%
-    let referral_reward = amount * 10 / 100;
-    referral_reward
+/// 计算提前解押的罚金比例（基点）：质押开始时为 max_penalty_bps，到期时线性递减到 0
+pub fn calculate_early_unstake_penalty_bps(
+    max_penalty_bps: u16,
+    stake_start_time: u64,
+    stake_end_time: u64,
+    current_timestamp: u64,
+) -> Result<u64> {
+    if current_timestamp >= stake_end_time {
+        return Ok(0);
+    }
+    let total_duration = stake_end_time
+        .checked_sub(stake_start_time)
+        .ok_or(StakingError::TimeCalculationError)?;
+    if total_duration == 0 {
+        return Ok(0);
+    }
+    // 早于开始时间（理论上不会出现）按剩余整个期限计算
+    let remaining = stake_end_time
+        .checked_sub(current_timestamp.max(stake_start_time))
+        .ok_or(StakingError::TimeCalculationError)?;
+
+    Ok(((max_penalty_bps as u128)
+        .checked_mul(remaining as u128)
+        .ok_or(StakingError::Overflow)?
+        / total_duration as u128) as u64)
+}
+
+/// 由质押实例PDA签名，从合约金库转出代币（数量为 0 时跳过）
+pub fn transfer_from_staking_vault<'info>(
+    token_program: &Program<'info, Token>,
+    vault: &Account<'info, TokenAccount>,
+    to: AccountInfo<'info>,
+    staking_instance: AccountInfo<'info>,
+    signer_seeds: &[&[&[u8]]],
+    amount: u64,
+) -> Result<()> {
+    if amount == 0 {
+        return Ok(());
+    }
+    let cpi_accounts = token::Transfer {
+        from: vault.to_account_info(),
+        to,
+        authority: staking_instance,
+    };
+    let cpi_ctx = CpiContext::new_with_signer(
+        token_program.to_account_info(),
+        cpi_accounts,
+        signer_seeds,
+    );
+    token::transfer(cpi_ctx, amount)
+}
+
+/// 加载并校验上级的用户账户：必须是上级地址对应的 User PDA；
+/// 上级从未初始化用户账户（旧版推荐关系）或尚未迁移旧版布局时返回 None
+pub fn load_upline_user<'info>(
+    user_info: &'info AccountInfo<'info>,
+    upline: Pubkey,
+) -> Result<Option<Account<'info, User>>> {
+    let (expected_key, _) =
+        Pubkey::find_program_address(&[b"user", upline.as_ref()], &crate::ID);
+    require_keys_eq!(user_info.key(), expected_key, StakingError::InvalidReferralAccount);
+
+    // 旧版布局与新布局判别符相同，必须按长度区分，否则会把旧版数据误读为新布局
+    if user_info.data_is_empty() || user_info.data_len() != User::LEN {
+        return Ok(None);
+    }
+
+    let upline_instance = Account::<User>::try_from(user_info)?;
+    require!(
+        upline_instance.isinit && upline_instance.user_address == upline,
+        StakingError::InvalidReferralAccount
+    );
+    Ok(Some(upline_instance))
+}
+
+/// 加载并校验上级接收奖励的代币账户：mint 为奖励代币且归上级所有
+pub fn load_upline_token_account<'info>(
+    token_info: &'info AccountInfo<'info>,
+    upline: Pubkey,
+    reward_token_mint: Pubkey,
+) -> Result<Account<'info, TokenAccount>> {
+    let token_account = Account::<TokenAccount>::try_from(token_info)?;
+    require_keys_eq!(token_account.mint, reward_token_mint, StakingError::MintAccountIsNotMatch);
+    require_keys_eq!(token_account.owner, upline, StakingError::InvalidVaultOwner);
+    Ok(token_account)
+}
+
+/// 在传入的仓位地址上写入质押仓位（migrate_user 一次迁移的仓位数量不定，无法用 Anchor `init` 声明）。
+/// 地址必须是 ["stake_position", 仓位所有者, 仓位编号] 派生的PDA；
+/// 租金只补差额，地址上预先存有 lamports 时也能创建
+pub fn init_stake_position<'info>(
+    payer: &AccountInfo<'info>,
+    position_info: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    position: &StakePosition,
+) -> Result<()> {
+    let position_id_bytes = position.position_id.to_le_bytes();
+    let (expected_key, bump) = Pubkey::find_program_address(
+        &[b"stake_position", position.owner.as_ref(), position_id_bytes.as_ref()],
+        &crate::ID,
+    );
+    require_keys_eq!(position_info.key(), expected_key, StakingError::PdaAccountIsNotMatch);
+    let signer_seeds: &[&[&[u8]]] =
+        &[&[b"stake_position", position.owner.as_ref(), position_id_bytes.as_ref(), &[bump]]];
+
+    let rent_lamports = Rent::get()?.minimum_balance(StakePosition::LEN);
+    let top_up = rent_lamports.saturating_sub(position_info.lamports());
+    if top_up > 0 {
+        anchor_lang::system_program::transfer(
+            CpiContext::new(
+                system_program.clone(),
+                anchor_lang::system_program::Transfer {
+                    from: payer.clone(),
+                    to: position_info.clone(),
+                },
+            ),
+            top_up,
+        )?;
+    }
+    anchor_lang::system_program::allocate(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            anchor_lang::system_program::Allocate {
+                account_to_allocate: position_info.clone(),
+            },
+            signer_seeds,
+        ),
+        StakePosition::LEN as u64,
+    )?;
+    anchor_lang::system_program::assign(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            anchor_lang::system_program::Assign {
+                account_to_assign: position_info.clone(),
+            },
+            signer_seeds,
+        ),
+        &crate::ID,
+    )?;
+
+    let mut position_data = position_info.try_borrow_mut_data()?;
+    position.try_serialize(&mut &mut position_data[..])?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn early_unstake_penalty_is_max_at_start() {
+        assert_eq!(calculate_early_unstake_penalty_bps(1000, 100, 1100, 100).unwrap(), 1000);
+    }
+
+    #[test]
+    fn early_unstake_penalty_halves_at_midpoint() {
+        assert_eq!(calculate_early_unstake_penalty_bps(1000, 100, 1100, 600).unwrap(), 500);
+    }
+
+    #[test]
+    fn early_unstake_penalty_is_zero_at_end() {
+        assert_eq!(calculate_early_unstake_penalty_bps(1000, 100, 1100, 1100).unwrap(), 0);
+        assert_eq!(calculate_early_unstake_penalty_bps(1000, 100, 1100, 2000).unwrap(), 0);
+    }
 }
@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+
+use super::errors::StakingError;
+use crate::structures::utils::transfer_from_staking_vault;
+
+use super::*;
+
+#[derive(Accounts)]
+pub struct WithdrawLpRewardVault<'info> {
+    /// 全局质押状态
+    #[account(
+        seeds = [crate::constants::STAKING_SEED],
+        bump,
+        constraint = staking_instance.is_initialized @ StakingError::StakingInstanceNotInitialized,
+        constraint = staking_instance.authority == authority.key() @ StakingError::Unauthorized
+    )]
+    pub staking_instance: Account<'info, StakingInstance>,
+
+    /// LP 奖励金库，必须是质押实例中记录的地址
+    #[account(
+        mut,
+        address = staking_instance.lp_reward_vault @ StakingError::InvalidLpRewardVault
+    )]
+    pub lp_reward_vault: Account<'info, TokenAccount>,
+
+    /// 接收 LP 的代币账户（由管理员指定，用于分发给质押者或其他用途）
+    #[account(
+        mut,
+        constraint = destination.mint == staking_instance.staking_token_mint @ StakingError::MintAccountIsNotMatch
+    )]
+    pub destination: Account<'info, TokenAccount>,
+
+    /// 管理员
+    pub authority: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+impl<'info> WithdrawLpRewardVault<'info> {
+    pub fn process(&mut self, amount: u64, bump_seed: u8) -> Result<()> {
+        require!(amount > 0, StakingError::InvalidStakeAmount);
+        require!(
+            self.lp_reward_vault.amount >= amount,
+            StakingError::TokenAccountBalanceInsufficient
+        );
+
+        let signer_seeds: &[&[&[u8]]] = &[&[crate::STAKING_SEED.as_ref(), &[bump_seed]]];
+        transfer_from_staking_vault(
+            &self.token_program,
+            &self.lp_reward_vault,
+            self.destination.to_account_info(),
+            self.staking_instance.to_account_info(),
+            signer_seeds,
+            amount,
+        )?;
+
+        msg!(
+            "Withdrew {} LP from LP reward vault to {:?}",
+            amount,
+            self.destination.key()
+        );
+        Ok(())
+    }
+}
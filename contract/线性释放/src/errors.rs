@@ -38,4 +38,61 @@ pub enum VestingError {
 
     #[msg("Invalid Vesting Period")]
     InvalidVestingPeriod,
+
+    #[msg("Invalid cliff duration: must be between 0 and the total vesting duration")]
+    InvalidCliffDuration,
+
+    #[msg("Vesting schedule is irrevocable and cannot be cancelled")]
+    VestingNotRevocable,
+
+    #[msg("Invalid beneficiary")]
+    InvalidBeneficiary,
+
+    #[msg("Invalid milestones: timestamps must increase, cumulative bps must be monotonic and reach 10000")]
+    InvalidMilestones,
+
+    #[msg("Invalid vesting schedule or vault account")]
+    InvalidScheduleAccount,
+
+    #[msg("Campaign has insufficient unallocated balance")]
+    CampaignInsufficientBalance,
+
+    #[msg("Nothing to reclaim")]
+    NothingToReclaim,
+
+    #[msg("Invalid merkle proof")]
+    InvalidMerkleProof,
+
+    #[msg("Claims are paused by the admin")]
+    ClaimsPaused,
+
+    #[msg("Vesting schedule is frozen")]
+    ScheduleFrozen,
+
+    #[msg("Crank tip exceeds the maximum allowed")]
+    InvalidCrankTip,
+
+    #[msg("Claim amount exceeds the claimable amount")]
+    ClaimAmountExceedsClaimable,
+
+    #[msg("New vesting terms would reduce the already vested amount")]
+    TopUpReducesVested,
+
+    #[msg("Vesting schedule has not been fully claimed")]
+    VestingNotFullyClaimed,
+
+    #[msg("Invalid voter weight record")]
+    InvalidVoterWeightRecord,
+
+    #[msg("Invalid claim deadline: must be 0 or in the future")]
+    InvalidClaimDeadline,
+
+    #[msg("Distributor claim deadline has passed")]
+    ClaimDeadlinePassed,
+
+    #[msg("Distributor claim deadline has not passed yet")]
+    ClaimDeadlineNotReached,
+
+    #[msg("Vesting schedule has already been migrated")]
+    ScheduleAlreadyMigrated,
 }
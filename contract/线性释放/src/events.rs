@@ -0,0 +1,265 @@
+use anchor_lang::prelude::*;
+use crate::state::{VestingSchedule, VestingPeriod};
+
+/// 释放计划创建事件
+#[event]
+pub struct VestingCreated {
+    /// 释放计划账户
+    pub vesting_schedule: Pubkey,
+    /// 创建者
+    pub creator: Pubkey,
+    /// 受益人
+    pub beneficiary: Pubkey,
+    /// 代币mint
+    pub mint: Pubkey,
+    /// 计划编号
+    pub schedule_id: u64,
+    /// 总金额（托管账户实际到账金额）
+    pub total_amount: u64,
+    /// 开始时间
+    pub start_time: i64,
+    /// 锁定期时长（秒）
+    pub cliff_duration: i64,
+    /// 释放周期类型
+    pub vesting_period: VestingPeriod,
+    /// 释放周期数量
+    pub period_count: u32,
+    /// 是否可撤销
+    pub revocable: bool,
+    /// 创建时间
+    pub timestamp: i64,
+}
+
+impl VestingCreated {
+    /// 根据已初始化的释放计划构造事件
+    pub fn from_schedule(vesting_schedule_key: Pubkey, vesting_schedule: &VestingSchedule) -> Self {
+        Self {
+            vesting_schedule: vesting_schedule_key,
+            creator: vesting_schedule.creator,
+            beneficiary: vesting_schedule.beneficiary,
+            mint: vesting_schedule.mint,
+            schedule_id: vesting_schedule.schedule_id,
+            total_amount: vesting_schedule.total_amount,
+            start_time: vesting_schedule.start_time,
+            cliff_duration: vesting_schedule.cliff_duration,
+            vesting_period: vesting_schedule.vesting_period,
+            period_count: vesting_schedule.period_count,
+            revocable: vesting_schedule.revocable,
+            timestamp: vesting_schedule.created_at,
+        }
+    }
+}
+
+/// 代币提取事件
+#[event]
+pub struct TokensClaimed {
+    /// 释放计划账户
+    pub vesting_schedule: Pubkey,
+    /// 受益人
+    pub beneficiary: Pubkey,
+    /// 本次提取金额
+    pub amount: u64,
+    /// 累计已提取金额
+    pub claimed_amount: u64,
+    /// 总金额
+    pub total_amount: u64,
+    /// 释放进度（百分比）
+    pub progress: u8,
+    /// 提取时间
+    pub timestamp: i64,
+}
+
+/// 代为提取事件
+#[event]
+pub struct ClaimCranked {
+    /// 释放计划账户
+    pub vesting_schedule: Pubkey,
+    /// 调用者
+    pub caller: Pubkey,
+    /// 发给受益人的金额
+    pub beneficiary_amount: u64,
+    /// 调用者获得的小费
+    pub tip_amount: u64,
+    /// 提取时间
+    pub timestamp: i64,
+}
+
+/// 释放计划追加事件
+#[event]
+pub struct VestingToppedUp {
+    /// 释放计划账户
+    pub vesting_schedule: Pubkey,
+    /// 追加金额（托管账户实际到账金额）
+    pub added_amount: u64,
+    /// 追加后总金额
+    pub total_amount: u64,
+    /// 追加后周期数
+    pub period_count: u32,
+    /// 追加后每周期金额
+    pub amount_per_period: u64,
+    /// 追加时间
+    pub timestamp: i64,
+}
+
+/// 释放计划关闭事件
+#[event]
+pub struct VestingClosed {
+    /// 释放计划账户
+    pub vesting_schedule: Pubkey,
+    /// 创建者（接收租金）
+    pub creator: Pubkey,
+    /// 总金额
+    pub total_amount: u64,
+    /// 关闭时间
+    pub timestamp: i64,
+}
+
+/// 治理投票权委托变更事件
+#[event]
+pub struct VoteDelegateChanged {
+    /// 释放计划账户
+    pub vesting_schedule: Pubkey,
+    /// 受益人
+    pub beneficiary: Pubkey,
+    /// 新的投票权重记录（取消委托时为默认地址）
+    pub voter_weight_record: Pubkey,
+    /// 计入的权重
+    pub weight: u64,
+    /// 变更时间
+    pub timestamp: i64,
+}
+
+/// 释放计划取消事件
+#[event]
+pub struct VestingCancelled {
+    /// 释放计划账户
+    pub vesting_schedule: Pubkey,
+    /// 创建者
+    pub creator: Pubkey,
+    /// 受益人
+    pub beneficiary: Pubkey,
+    /// 发给受益人的已释放未提取金额
+    pub beneficiary_amount: u64,
+    /// 退还给创建者的未释放金额
+    pub refunded_amount: u64,
+    /// 取消时间
+    pub timestamp: i64,
+}
+
+/// 受益人转移事件
+#[event]
+pub struct BeneficiaryTransferred {
+    /// 释放计划账户
+    pub vesting_schedule: Pubkey,
+    /// 原受益人
+    pub old_beneficiary: Pubkey,
+    /// 新受益人
+    pub new_beneficiary: Pubkey,
+    /// 发起转移的签名者（受益人或创建者）
+    pub authority: Pubkey,
+    /// 转移时间
+    pub timestamp: i64,
+}
+
+/// 全局暂停状态变更事件
+#[event]
+pub struct PauseStatusChanged {
+    /// 管理员
+    pub admin: Pubkey,
+    /// 是否暂停
+    pub paused: bool,
+    /// 变更时间
+    pub timestamp: i64,
+}
+
+/// 管理员转移事件
+#[event]
+pub struct AdminTransferred {
+    /// 原管理员
+    pub old_admin: Pubkey,
+    /// 新管理员
+    pub new_admin: Pubkey,
+    /// 变更时间
+    pub timestamp: i64,
+}
+
+/// 释放计划冻结状态变更事件
+#[event]
+pub struct ScheduleFreezeChanged {
+    /// 释放计划账户
+    pub vesting_schedule: Pubkey,
+    /// 管理员
+    pub admin: Pubkey,
+    /// 是否冻结
+    pub frozen: bool,
+    /// 变更时间
+    pub timestamp: i64,
+}
+
+/// 全局配置初始化事件
+#[event]
+pub struct ConfigInitialized {
+    /// 管理员
+    pub admin: Pubkey,
+    /// 初始化时间
+    pub timestamp: i64,
+}
+
+/// 批量释放活动创建事件
+#[event]
+pub struct CampaignCreated {
+    /// 活动账户
+    pub vesting_campaign: Pubkey,
+    /// 创建者
+    pub creator: Pubkey,
+    /// 代币mint
+    pub mint: Pubkey,
+    /// 活动编号
+    pub campaign_id: u64,
+    /// 注资总额（托管账户实际到账金额）
+    pub total_amount: u64,
+    /// 开始时间
+    pub start_time: i64,
+    /// 锁定期时长（秒）
+    pub cliff_duration: i64,
+    /// 释放周期类型
+    pub vesting_period: VestingPeriod,
+    /// 释放周期数量
+    pub period_count: u32,
+    /// 创建时间
+    pub timestamp: i64,
+}
+
+/// Merkle 分发创建事件
+#[event]
+pub struct DistributorCreated {
+    /// 分发账户
+    pub distributor: Pubkey,
+    /// 创建者
+    pub creator: Pubkey,
+    /// 代币mint
+    pub mint: Pubkey,
+    /// 分发编号
+    pub distributor_id: u64,
+    /// Merkle 根
+    pub root: [u8; 32],
+    /// 注资总额（托管账户实际到账金额）
+    pub total_amount: u64,
+    /// 领取截止时间
+    pub claim_deadline: i64,
+    /// 创建时间
+    pub timestamp: i64,
+}
+
+/// 剩余代币取回事件
+#[event]
+pub struct RemainderReclaimed {
+    /// 来源账户（批量释放活动或 Merkle 分发）
+    pub source: Pubkey,
+    /// 取回金额
+    pub amount: u64,
+    /// 接收者（创建者）
+    pub recipient: Pubkey,
+    /// 取回时间
+    pub timestamp: i64,
+}
@@ -0,0 +1,220 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    self, GetAccountDataSize, InitializeAccount3, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+use crate::state::{VestingCampaign, VestingSchedule, CampaignAllocation};
+use crate::errors::VestingError;
+use crate::events::VestingCreated;
+use crate::utils::{create_pda_account, schedule_id_seed};
+
+/// 从批量释放活动中为一批受益人创建释放计划
+/// remaining_accounts 按分配顺序依次传入 [释放计划PDA, 托管账户PDA]，均为可写且尚未创建
+pub fn add_campaign_schedules<'info>(
+    ctx: Context<'_, '_, 'info, 'info, AddCampaignSchedules<'info>>,
+    allocations: Vec<CampaignAllocation>,
+) -> Result<()> {
+    let clock = Clock::get()?;
+
+    require!(!allocations.is_empty(), VestingError::InvalidAmount);
+    require!(
+        ctx.remaining_accounts.len() == allocations.len() * 2,
+        VestingError::InvalidScheduleAccount
+    );
+
+    // 检查本批次分配总额不超过活动剩余额度
+    let mut batch_amount: u64 = 0;
+    for allocation in allocations.iter() {
+        require!(allocation.amount > 0, VestingError::InvalidAmount);
+        batch_amount = batch_amount
+            .checked_add(allocation.amount)
+            .ok_or(VestingError::MathOverflow)?;
+    }
+    require!(
+        batch_amount <= ctx.accounts.vesting_campaign.get_remaining_amount(),
+        VestingError::CampaignInsufficientBalance
+    );
+
+    let creator_key = ctx.accounts.creator.key();
+    let mint_key = ctx.accounts.mint.key();
+
+    // 生成活动PDA签名种子
+    let campaign_id_bytes = ctx.accounts.vesting_campaign.campaign_id.to_le_bytes();
+    let campaign_seeds = &[
+        b"campaign",
+        creator_key.as_ref(),
+        mint_key.as_ref(),
+        campaign_id_bytes.as_ref(),
+        &[ctx.bumps.vesting_campaign],
+    ];
+    let campaign_signer_seeds = &[&campaign_seeds[..]];
+
+    // 托管代币账户大小（Token-2022 的mint扩展可能要求代币账户携带额外扩展）
+    let vault_space = token_interface::get_account_data_size(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            GetAccountDataSize {
+                mint: ctx.accounts.mint.to_account_info(),
+            },
+        ),
+        &[],
+    )?;
+
+    for (allocation, accounts) in allocations.iter().zip(ctx.remaining_accounts.chunks(2)) {
+        let vesting_schedule_info = &accounts[0];
+        let vault_token_account_info = &accounts[1];
+
+        // 校验释放计划PDA（与单独创建的释放计划种子一致）
+        let schedule_id_seed = schedule_id_seed(allocation.schedule_id);
+        let (vesting_schedule_key, vesting_schedule_bump) = Pubkey::find_program_address(
+            &[
+                b"vesting",
+                creator_key.as_ref(),
+                allocation.beneficiary.as_ref(),
+                mint_key.as_ref(),
+                schedule_id_seed.as_ref(),
+            ],
+            ctx.program_id,
+        );
+        require_keys_eq!(
+            vesting_schedule_info.key(),
+            vesting_schedule_key,
+            VestingError::InvalidScheduleAccount
+        );
+
+        // 校验托管账户PDA
+        let (vault_key, vault_bump) = Pubkey::find_program_address(
+            &[b"vault", vesting_schedule_key.as_ref()],
+            ctx.program_id,
+        );
+        require_keys_eq!(
+            vault_token_account_info.key(),
+            vault_key,
+            VestingError::InvalidScheduleAccount
+        );
+
+        // 创建释放计划账户
+        let vesting_schedule_seeds = &[
+            b"vesting",
+            creator_key.as_ref(),
+            allocation.beneficiary.as_ref(),
+            mint_key.as_ref(),
+            schedule_id_seed.as_ref(),
+            &[vesting_schedule_bump],
+        ];
+        create_pda_account(
+            &ctx.accounts.creator.to_account_info(),
+            vesting_schedule_info,
+            &ctx.accounts.system_program.to_account_info(),
+            VestingSchedule::LEN as u64,
+            ctx.program_id,
+            &vesting_schedule_seeds[..],
+        )?;
+
+        // 创建托管代币账户，所有者为释放计划PDA
+        let vault_seeds = &[b"vault", vesting_schedule_key.as_ref(), &[vault_bump]];
+        create_pda_account(
+            &ctx.accounts.creator.to_account_info(),
+            vault_token_account_info,
+            &ctx.accounts.system_program.to_account_info(),
+            vault_space,
+            &ctx.accounts.token_program.key(),
+            &vault_seeds[..],
+        )?;
+        token_interface::initialize_account3(CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            InitializeAccount3 {
+                account: vault_token_account_info.clone(),
+                mint: ctx.accounts.mint.to_account_info(),
+                authority: vesting_schedule_info.clone(),
+            },
+        ))?;
+
+        // 从活动托管账户划转分配金额
+        let transfer_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.campaign_vault.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                to: vault_token_account_info.clone(),
+                authority: ctx.accounts.vesting_campaign.to_account_info(),
+            },
+            campaign_signer_seeds,
+        );
+        token_interface::transfer_checked(transfer_ctx, allocation.amount, ctx.accounts.mint.decimals)?;
+
+        // 按托管账户实际到账金额写入释放计划（Token-2022 转账手续费）
+        let received_amount =
+            TokenAccount::try_deserialize(&mut &vault_token_account_info.try_borrow_data()?[..])?.amount;
+        require!(received_amount > 0, VestingError::InvalidAmount);
+
+        let vesting_schedule = ctx.accounts.vesting_campaign.build_schedule(
+            allocation.beneficiary,
+            allocation.schedule_id,
+            received_amount,
+            clock.unix_timestamp,
+        );
+        vesting_schedule.try_serialize(&mut &mut vesting_schedule_info.try_borrow_mut_data()?[..])?;
+
+        emit!(VestingCreated::from_schedule(vesting_schedule_info.key(), &vesting_schedule));
+
+        msg!(
+            "Campaign schedule #{} created for {}: {} tokens",
+            allocation.schedule_id,
+            allocation.beneficiary,
+            received_amount
+        );
+    }
+
+    // 更新活动分配记录
+    let vesting_campaign = &mut ctx.accounts.vesting_campaign;
+    vesting_campaign.allocated_amount = vesting_campaign
+        .allocated_amount
+        .checked_add(batch_amount)
+        .ok_or(VestingError::MathOverflow)?;
+    vesting_campaign.schedule_count = vesting_campaign
+        .schedule_count
+        .checked_add(allocations.len() as u32)
+        .ok_or(VestingError::MathOverflow)?;
+
+    msg!(
+        "Campaign #{} allocated {} tokens to {} beneficiaries, remaining {}",
+        vesting_campaign.campaign_id,
+        batch_amount,
+        allocations.len(),
+        vesting_campaign.get_remaining_amount()
+    );
+    Ok(())
+}
+
+/// 批量添加释放计划的账户验证（仅活动创建者可用）
+#[derive(Accounts)]
+pub struct AddCampaignSchedules<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    /// 代币mint
+    #[account(
+        constraint = mint.key() == vesting_campaign.mint @ VestingError::InvalidTokenMint
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// 批量释放活动账户
+    #[account(
+        mut,
+        constraint = vesting_campaign.creator == creator.key() @ VestingError::Unauthorized,
+        seeds = [b"campaign", creator.key().as_ref(), vesting_campaign.mint.as_ref(), vesting_campaign.campaign_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub vesting_campaign: Account<'info, VestingCampaign>,
+
+    /// 活动托管代币账户
+    #[account(
+        mut,
+        seeds = [b"campaign_vault", vesting_campaign.key().as_ref()],
+        bump
+    )]
+    pub campaign_vault: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,84 @@
+use anchor_lang::prelude::*;
+use crate::state::VestingSchedule;
+use crate::errors::VestingError;
+use crate::events::VestingCancelled;
+use crate::utils::{is_claims_paused, format_timestamp};
+
+/// 取消原生 SOL 释放计划（仅创建者可用，且计划必须可撤销）
+/// 已释放未提取的 lamports 发给受益人，未释放的 lamports 随账户关闭退还给创建者
+pub fn cancel_sol_vesting(ctx: Context<CancelSolVesting>) -> Result<()> {
+    let vesting_schedule = &ctx.accounts.vesting_schedule;
+    let clock = Clock::get()?;
+
+    // 不可撤销的计划不能取消
+    require!(vesting_schedule.revocable, VestingError::VestingNotRevocable);
+
+    // 全局暂停或计划冻结期间资金不能移动
+    require!(!is_claims_paused(&ctx.accounts.config)?, VestingError::ClaimsPaused);
+    require!(!vesting_schedule.frozen, VestingError::ScheduleFrozen);
+
+    // 已释放但未提取的金额归受益人
+    let vested_unclaimed_amount = vesting_schedule.get_claimable_amount(clock.unix_timestamp)?;
+
+    // 未释放的金额退还给创建者
+    let unvested_amount = vesting_schedule.get_locked_amount(clock.unix_timestamp)?;
+
+    if vested_unclaimed_amount > 0 {
+        let schedule_info = ctx.accounts.vesting_schedule.to_account_info();
+        let beneficiary_info = ctx.accounts.beneficiary.to_account_info();
+        **schedule_info.try_borrow_mut_lamports()? = schedule_info
+            .lamports()
+            .checked_sub(vested_unclaimed_amount)
+            .ok_or(VestingError::InsufficientBalance)?;
+        **beneficiary_info.try_borrow_mut_lamports()? = beneficiary_info
+            .lamports()
+            .checked_add(vested_unclaimed_amount)
+            .ok_or(VestingError::MathOverflow)?;
+    }
+
+    emit!(VestingCancelled {
+        vesting_schedule: ctx.accounts.vesting_schedule.key(),
+        creator: ctx.accounts.vesting_schedule.creator,
+        beneficiary: ctx.accounts.vesting_schedule.beneficiary,
+        beneficiary_amount: vested_unclaimed_amount,
+        refunded_amount: unvested_amount,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "SOL vesting schedule cancelled. Paid {} vested lamports to beneficiary, returned {} lamports to creator at {}",
+        vested_unclaimed_amount,
+        unvested_amount,
+        format_timestamp(clock.unix_timestamp)
+    );
+    Ok(())
+}
+
+/// 取消原生 SOL 释放计划的账户验证（仅创建者可用）
+#[derive(Accounts)]
+pub struct CancelSolVesting<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    /// CHECK: 全局配置账户PDA，尚未初始化时视为未暂停，已初始化时在指令中读取暂停状态
+    #[account(seeds = [b"config"], bump)]
+    pub config: UncheckedAccount<'info>,
+
+    /// 释放计划账户（关闭时剩余 lamports 含未释放部分和租金退还给创建者）
+    #[account(
+        mut,
+        constraint = vesting_schedule.is_native() @ VestingError::InvalidTokenMint,
+        constraint = vesting_schedule.creator == creator.key() @ VestingError::Unauthorized,
+        seeds = [vesting_schedule.seed_prefix(), vesting_schedule.seed_authority().as_ref(), vesting_schedule.initial_beneficiary.as_ref(), vesting_schedule.mint.as_ref(), vesting_schedule.schedule_id_seed().as_ref()],
+        bump,
+        close = creator
+    )]
+    pub vesting_schedule: Account<'info, VestingSchedule>,
+
+    /// CHECK: 受益人钱包，与释放计划中的当前受益人一致
+    #[account(
+        mut,
+        constraint = beneficiary.key() == vesting_schedule.beneficiary @ VestingError::Unauthorized
+    )]
+    pub beneficiary: UncheckedAccount<'info>,
+}
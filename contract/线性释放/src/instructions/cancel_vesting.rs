@@ -1,46 +1,88 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Token, TokenAccount, Transfer};
-use crate::state::VestingSchedule;
+use anchor_spl::token_interface::{self, Mint, TokenInterface, TokenAccount, TransferChecked};
+use crate::state::{VestingSchedule, VoterWeightRecord};
 use crate::errors::VestingError;
-use crate::utils::format_timestamp;
+use crate::events::VestingCancelled;
+use crate::utils::{is_claims_paused, format_timestamp, sync_voter_weight};
 
-/// 取消释放计划（仅创建者可用，将剩余代币退还给创建者）
+/// 取消释放计划（仅创建者可用，且计划必须可撤销）
+/// 已释放未提取的代币发给受益人，未释放的代币退还给创建者
 pub fn cancel_vesting(ctx: Context<CancelVesting>) -> Result<()> {
     let vesting_schedule = &ctx.accounts.vesting_schedule;
     let clock = Clock::get()?;
 
-    // 计算需要退还的金额（总金额 - 已提取金额）
-    let remaining_amount = vesting_schedule.total_amount
-        .checked_sub(vesting_schedule.claimed_amount)
-        .ok_or(VestingError::MathOverflow)?;
-
-    if remaining_amount > 0 {
-        // 生成PDA签名种子
-        let seeds = &[
-            b"vesting",
-            vesting_schedule.creator.as_ref(),
-            vesting_schedule.beneficiary.as_ref(),
-            vesting_schedule.mint.as_ref(),
-            &[ctx.bumps.vesting_schedule],
-        ];
-        let signer_seeds = &[&seeds[..]];
-
-        // 将剩余代币退还给创建者
+    // 不可撤销的计划不能取消
+    require!(vesting_schedule.revocable, VestingError::VestingNotRevocable);
+
+    // 全局暂停或计划冻结期间资金不能移动
+    require!(!is_claims_paused(&ctx.accounts.config)?, VestingError::ClaimsPaused);
+    require!(!vesting_schedule.frozen, VestingError::ScheduleFrozen);
+
+    // 已释放但未提取的金额归受益人
+    let vested_unclaimed_amount = vesting_schedule.get_claimable_amount(clock.unix_timestamp)?;
+
+    // 未释放的金额退还给创建者（总金额 - 已提取金额 - 已释放未提取金额）
+    let unvested_amount = vesting_schedule.get_locked_amount(clock.unix_timestamp)?;
+
+    // 生成PDA签名种子
+    let schedule_id_seed = vesting_schedule.schedule_id_seed();
+    let seeds = &[
+        vesting_schedule.seed_prefix(),
+        vesting_schedule.seed_authority().as_ref(),
+        vesting_schedule.initial_beneficiary.as_ref(),
+        vesting_schedule.mint.as_ref(),
+        schedule_id_seed.as_ref(),
+        &[ctx.bumps.vesting_schedule],
+    ];
+    let signer_seeds = &[&seeds[..]];
+
+    if vested_unclaimed_amount > 0 {
+        // 将已释放未提取的代币发给受益人
         let transfer_ctx = CpiContext::new_with_signer(
             ctx.accounts.token_program.to_account_info(),
-            Transfer {
+            TransferChecked {
                 from: ctx.accounts.vault_token_account.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                to: ctx.accounts.beneficiary_token_account.to_account_info(),
+                authority: ctx.accounts.vesting_schedule.to_account_info(),
+            },
+            signer_seeds,
+        );
+        token_interface::transfer_checked(transfer_ctx, vested_unclaimed_amount, ctx.accounts.mint.decimals)?;
+    }
+
+    if unvested_amount > 0 {
+        // 将未释放的代币退还给创建者
+        let transfer_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.vault_token_account.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
                 to: ctx.accounts.creator_token_account.to_account_info(),
                 authority: ctx.accounts.vesting_schedule.to_account_info(),
             },
             signer_seeds,
         );
-        token::transfer(transfer_ctx, remaining_amount)?;
+        token_interface::transfer_checked(transfer_ctx, unvested_amount, ctx.accounts.mint.decimals)?;
     }
 
+    // 计划关闭后从投票权重记录中移除本计划的权重
+    let accounts = &mut *ctx.accounts;
+    sync_voter_weight(&mut accounts.vesting_schedule, accounts.voter_weight_record.as_mut(), 0)?;
+
+    emit!(VestingCancelled {
+        vesting_schedule: ctx.accounts.vesting_schedule.key(),
+        creator: ctx.accounts.vesting_schedule.creator,
+        beneficiary: ctx.accounts.vesting_schedule.beneficiary,
+        beneficiary_amount: vested_unclaimed_amount,
+        refunded_amount: unvested_amount,
+        timestamp: clock.unix_timestamp,
+    });
+
     msg!(
-        "Vesting schedule cancelled. Returned {} tokens to creator at {}",
-        remaining_amount,
+        "Vesting schedule cancelled. Paid {} vested tokens to beneficiary, returned {} tokens to creator at {}",
+        vested_unclaimed_amount,
+        unvested_amount,
         format_timestamp(clock.unix_timestamp)
     );
     Ok(())
@@ -51,17 +93,27 @@ pub fn cancel_vesting(ctx: Context<CancelVesting>) -> Result<()> {
 pub struct CancelVesting<'info> {
     #[account(mut)]
     pub creator: Signer<'info>,
-    
+
+    /// CHECK: 全局配置账户PDA，尚未初始化时视为未暂停，已初始化时在指令中读取暂停状态
+    #[account(seeds = [b"config"], bump)]
+    pub config: UncheckedAccount<'info>,
+
     /// 释放计划账户
     #[account(
         mut,
         constraint = vesting_schedule.creator == creator.key() @ VestingError::Unauthorized,
-        seeds = [b"vesting", creator.key().as_ref(), vesting_schedule.beneficiary.as_ref(), vesting_schedule.mint.as_ref()],
+        seeds = [vesting_schedule.seed_prefix(), vesting_schedule.seed_authority().as_ref(), vesting_schedule.initial_beneficiary.as_ref(), vesting_schedule.mint.as_ref(), vesting_schedule.schedule_id_seed().as_ref()],
         bump,
         close = creator
     )]
     pub vesting_schedule: Account<'info, VestingSchedule>,
     
+    /// 代币mint
+    #[account(
+        constraint = mint.key() == vesting_schedule.mint @ VestingError::InvalidTokenMint
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+    
     /// 托管代币账户
     #[account(
         mut,
@@ -69,15 +121,27 @@ pub struct CancelVesting<'info> {
         seeds = [b"vault", vesting_schedule.key().as_ref()],
         bump
     )]
-    pub vault_token_account: Account<'info, TokenAccount>,
+    pub vault_token_account: InterfaceAccount<'info, TokenAccount>,
     
-    /// 创建者的代币账户（用于退还剩余代币）
+    /// 创建者的代币账户（用于退还未释放代币）
     #[account(
         mut,
         constraint = creator_token_account.mint == vesting_schedule.mint @ VestingError::InvalidTokenMint,
         constraint = creator_token_account.owner == creator.key() @ VestingError::Unauthorized
     )]
-    pub creator_token_account: Account<'info, TokenAccount>,
-    
-    pub token_program: Program<'info, Token>,
+    pub creator_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// 受益人的代币账户（用于接收已释放未提取代币）
+    #[account(
+        mut,
+        constraint = beneficiary_token_account.mint == vesting_schedule.mint @ VestingError::InvalidTokenMint,
+        constraint = beneficiary_token_account.owner == vesting_schedule.beneficiary @ VestingError::Unauthorized
+    )]
+    pub beneficiary_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// 已委托治理投票权时传入对应的投票权重记录，用于同步权重
+    #[account(mut)]
+    pub voter_weight_record: Option<Account<'info, VoterWeightRecord>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
 }
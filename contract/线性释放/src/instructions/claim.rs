@@ -1,16 +1,42 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Token, TokenAccount, Transfer};
-use crate::state::VestingSchedule;
+use anchor_spl::token_interface::{self, Mint, TokenInterface, TokenAccount, TransferChecked};
+use crate::state::{VestingSchedule, VoterWeightRecord};
 use crate::errors::VestingError;
+use crate::events::TokensClaimed;
+use crate::utils::{is_claims_paused, sync_voter_weight};
 
 /// 提取已释放的代币
 pub fn claim(ctx: Context<Claim>) -> Result<()> {
+    process_claim(ctx, None)
+}
+
+/// 提取指定数量的已释放代币，剩余部分保留在计划中稍后提取
+pub fn claim_partial(ctx: Context<Claim>, amount: u64) -> Result<()> {
+    process_claim(ctx, Some(amount))
+}
+
+/// 提取逻辑：amount 为 None 时提取全部可提取金额
+fn process_claim(ctx: Context<Claim>, amount: Option<u64>) -> Result<()> {
     let clock = Clock::get()?;
 
+    // 全局暂停或计划冻结期间不能提取
+    require!(!is_claims_paused(&ctx.accounts.config)?, VestingError::ClaimsPaused);
+    require!(!ctx.accounts.vesting_schedule.frozen, VestingError::ScheduleFrozen);
+
     // 计算可提取金额
-    let claimable_amount = ctx.accounts.vesting_schedule.get_claimable_amount(clock.unix_timestamp)?;
+    let available_amount = ctx.accounts.vesting_schedule.get_claimable_amount(clock.unix_timestamp)?;
 
-    require!(claimable_amount > 0, VestingError::NothingToClaim);
+    require!(available_amount > 0, VestingError::NothingToClaim);
+
+    // 指定数量时不能超过可提取金额
+    let claimable_amount = match amount {
+        Some(amount) => {
+            require!(amount > 0, VestingError::InvalidAmount);
+            require!(amount <= available_amount, VestingError::ClaimAmountExceedsClaimable);
+            amount
+        }
+        None => available_amount,
+    };
 
 
 
@@ -18,11 +44,13 @@ pub fn claim(ctx: Context<Claim>) -> Result<()> {
     require!(ctx.accounts.vesting_schedule.claimed_amount + claimable_amount <= ctx.accounts.vesting_schedule.total_amount, VestingError::MathOverflow);
 
     // 生成PDA签名种子
+    let schedule_id_seed = ctx.accounts.vesting_schedule.schedule_id_seed();
     let seeds = &[
-        b"vesting",
-        ctx.accounts.vesting_schedule.creator.as_ref(),
-        ctx.accounts.vesting_schedule.beneficiary.as_ref(),
+        ctx.accounts.vesting_schedule.seed_prefix(),
+        ctx.accounts.vesting_schedule.seed_authority().as_ref(),
+        ctx.accounts.vesting_schedule.initial_beneficiary.as_ref(),
         ctx.accounts.vesting_schedule.mint.as_ref(),
+        schedule_id_seed.as_ref(),
         &[ctx.bumps.vesting_schedule],
     ];
     let signer_seeds = &[&seeds[..]];
@@ -30,25 +58,38 @@ pub fn claim(ctx: Context<Claim>) -> Result<()> {
     // 从托管账户转移代币给受益人
     let transfer_ctx = CpiContext::new_with_signer(
         ctx.accounts.token_program.to_account_info(),
-        Transfer {
+        TransferChecked {
             from: ctx.accounts.vault_token_account.to_account_info(),
+            mint: ctx.accounts.mint.to_account_info(),
             to: ctx.accounts.beneficiary_token_account.to_account_info(),
             authority: ctx.accounts.vesting_schedule.to_account_info(),
         },
         signer_seeds,
     );
-    token::transfer(transfer_ctx, claimable_amount)?;
+    token_interface::transfer_checked(transfer_ctx, claimable_amount, ctx.accounts.mint.decimals)?;
 
     // 更新已提取金额
     ctx.accounts.vesting_schedule.claimed_amount = ctx.accounts.vesting_schedule.claimed_amount
         .checked_add(claimable_amount)
         .ok_or(VestingError::MathOverflow)?;
 
-    msg!(
-        "Claimed {} tokens. Progress: {}%",
-        claimable_amount,
-        ctx.accounts.vesting_schedule.get_vesting_progress(clock.unix_timestamp)
-    );
+    // 同步治理投票权重
+    let accounts = &mut *ctx.accounts;
+    let voting_weight = accounts.vesting_schedule.get_voting_weight();
+    sync_voter_weight(&mut accounts.vesting_schedule, accounts.voter_weight_record.as_mut(), voting_weight)?;
+
+    let progress = ctx.accounts.vesting_schedule.get_vesting_progress(clock.unix_timestamp);
+    emit!(TokensClaimed {
+        vesting_schedule: ctx.accounts.vesting_schedule.key(),
+        beneficiary: ctx.accounts.vesting_schedule.beneficiary,
+        amount: claimable_amount,
+        claimed_amount: ctx.accounts.vesting_schedule.claimed_amount,
+        total_amount: ctx.accounts.vesting_schedule.total_amount,
+        progress,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Claimed {} tokens. Progress: {}%", claimable_amount, progress);
     Ok(())
 }
 
@@ -57,28 +98,39 @@ pub fn claim(ctx: Context<Claim>) -> Result<()> {
 pub struct Claim<'info> {
     #[account(mut)]
     pub beneficiary: Signer<'info>,
+
+    /// CHECK: 全局配置账户PDA，尚未初始化时视为未暂停，已初始化时在指令中读取暂停状态
+    #[account(seeds = [b"config"], bump)]
+    pub config: UncheckedAccount<'info>,
     
     /// 释放计划账户
     #[account(
         mut,
         constraint = vesting_schedule.beneficiary == beneficiary.key() @ VestingError::Unauthorized,
         seeds = [
-    b"vesting",
-    vesting_schedule.creator.as_ref(),
-    vesting_schedule.beneficiary.as_ref(),
-    vesting_schedule.mint.as_ref(),
-  ],
+            vesting_schedule.seed_prefix(),
+            vesting_schedule.seed_authority().as_ref(),
+            vesting_schedule.initial_beneficiary.as_ref(),
+            vesting_schedule.mint.as_ref(),
+            vesting_schedule.schedule_id_seed().as_ref(),
+        ],
         bump
     )]
     pub vesting_schedule: Account<'info, VestingSchedule>,
     
+    /// 代币mint
+    #[account(
+        constraint = mint.key() == vesting_schedule.mint @ VestingError::InvalidTokenMint
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+    
     /// 托管代币账户
     #[account(
        mut,
         constraint = vault_token_account.mint == vesting_schedule.mint @ VestingError::InvalidTokenMint,
         constraint = vault_token_account.owner == vesting_schedule.key() @ VestingError::Unauthorized
     )]
-    pub vault_token_account: Account<'info, TokenAccount>,
+    pub vault_token_account: InterfaceAccount<'info, TokenAccount>,
     
     /// 受益人的代币账户
     #[account(
@@ -86,7 +138,11 @@ pub struct Claim<'info> {
         constraint = beneficiary_token_account.mint == vesting_schedule.mint @ VestingError::InvalidTokenMint,
         constraint = beneficiary_token_account.owner == beneficiary.key() @ VestingError::Unauthorized
     )]
-    pub beneficiary_token_account: Account<'info, TokenAccount>,
-    
-    pub token_program: Program<'info, Token>,
+    pub beneficiary_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// 已委托治理投票权时传入对应的投票权重记录，用于同步权重
+    #[account(mut)]
+    pub voter_weight_record: Option<Account<'info, VoterWeightRecord>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
 }
@@ -0,0 +1,268 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self, Mint, TokenInterface, TokenAccount, TransferChecked};
+use crate::state::{ClaimStatus, MerkleDistributor, MerkleLeaf, VestingSchedule, VestingPeriod};
+use crate::errors::VestingError;
+use crate::events::{VestingCreated, TokensClaimed};
+use crate::utils::{
+    is_claims_paused, compute_merkle_leaf, verify_merkle_proof, validate_cliff_duration, calculate_amount_per_period,
+    get_received_amount, MAX_CALENDAR_MONTHS,
+};
+use crate::instructions::create_vesting_schedule::validate_vesting_period;
+
+/// 受益人首次从 Merkle 分发提取：验证证明，创建释放计划并提取当前可提取的代币
+/// 每个叶子对应一个领取记录和一个释放计划，同一受益人在一棵树中的多个叶子可分别领取
+/// 之后的提取使用 `claim` 指令，按释放计划正常计算
+pub fn claim_from_distributor(
+    ctx: Context<ClaimFromDistributor>,
+    leaf: MerkleLeaf,
+    proof: Vec<[u8; 32]>,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let beneficiary = ctx.accounts.beneficiary.key();
+    let MerkleLeaf {
+        leaf_index,
+        amount,
+        start_time,
+        vesting_period,
+        period_count,
+        cliff_duration,
+    } = leaf;
+
+    // 全局暂停期间不能提取
+    require!(!is_claims_paused(&ctx.accounts.config)?, VestingError::ClaimsPaused);
+
+    // 领取截止后不能再创建释放计划
+    require!(
+        !ctx.accounts.distributor.is_claim_closed(clock.unix_timestamp),
+        VestingError::ClaimDeadlinePassed
+    );
+
+    // 验证 Merkle 证明
+    require!(
+        verify_merkle_proof(&proof, &ctx.accounts.distributor.root, compute_merkle_leaf(&beneficiary, &leaf)),
+        VestingError::InvalidMerkleProof
+    );
+
+    // 验证释放条款（Merkle 分发不支持自定义解锁表，开始时间可以早于当前时间）
+    validate_vesting_period(vesting_period)?;
+    require!(vesting_period != VestingPeriod::Custom, VestingError::InvalidVestingPeriod);
+    require!(amount > 0, VestingError::InvalidAmount);
+    require!(period_count > 0, VestingError::InvalidPeriodCount);
+    if let Some(months) = vesting_period.to_months() {
+        require!(
+            months * period_count as i64 <= MAX_CALENDAR_MONTHS,
+            VestingError::InvalidPeriodCount
+        );
+    }
+    require!(
+        amount <= ctx.accounts.distributor.get_remaining_amount(),
+        VestingError::InsufficientBalance
+    );
+
+    // 记录该叶子已领取（领取记录账户已存在时创建失败，防止重复领取）
+    let claim_status = &mut ctx.accounts.claim_status;
+    claim_status.distributor = ctx.accounts.distributor.key();
+    claim_status.leaf_index = leaf_index;
+    claim_status.claimant = beneficiary;
+    claim_status.amount = amount;
+    claim_status.claimed_at = clock.unix_timestamp;
+
+    // 创建释放计划，创建者为分发的创建者（可在全部提取后关闭计划回收租金），计划编号为叶子编号，不可撤销
+    let vesting_schedule = &mut ctx.accounts.vesting_schedule;
+    vesting_schedule.creator = ctx.accounts.distributor.creator;
+    vesting_schedule.beneficiary = beneficiary;
+    vesting_schedule.initial_beneficiary = beneficiary;
+    vesting_schedule.mint = ctx.accounts.mint.key();
+    vesting_schedule.schedule_id = leaf_index;
+    vesting_schedule.total_amount = amount;
+    vesting_schedule.claimed_amount = 0;
+    vesting_schedule.start_time = start_time;
+    vesting_schedule.cliff_duration = cliff_duration;
+    vesting_schedule.vesting_period = vesting_period;
+    vesting_schedule.period_count = period_count;
+    vesting_schedule.amount_per_period = calculate_amount_per_period(amount, vesting_period, period_count);
+    vesting_schedule.milestones = Vec::new();
+    vesting_schedule.revocable = false;
+    vesting_schedule.creator_can_transfer = false;
+    vesting_schedule.frozen = false;
+    vesting_schedule.crank_tip_bps = 0;
+    vesting_schedule.voter_weight_record = Pubkey::default();
+    vesting_schedule.delegated_weight = 0;
+    vesting_schedule.distributor = ctx.accounts.distributor.key();
+    vesting_schedule.created_at = clock.unix_timestamp;
+
+    validate_cliff_duration(cliff_duration, vesting_schedule.get_total_duration())?;
+
+    // 从分发托管账户划转到释放计划托管账户
+    let distributor_id_bytes = ctx.accounts.distributor.distributor_id.to_le_bytes();
+    let distributor_seeds = &[
+        b"distributor",
+        ctx.accounts.distributor.creator.as_ref(),
+        ctx.accounts.distributor.mint.as_ref(),
+        distributor_id_bytes.as_ref(),
+        &[ctx.bumps.distributor],
+    ];
+    let transfer_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        TransferChecked {
+            from: ctx.accounts.distributor_vault.to_account_info(),
+            mint: ctx.accounts.mint.to_account_info(),
+            to: ctx.accounts.vault_token_account.to_account_info(),
+            authority: ctx.accounts.distributor.to_account_info(),
+        },
+        &[&distributor_seeds[..]],
+    );
+    token_interface::transfer_checked(transfer_ctx, amount, ctx.accounts.mint.decimals)?;
+
+    // 按释放计划托管账户实际到账金额记录（Token-2022 转账手续费）
+    let received_amount = get_received_amount(&mut ctx.accounts.vault_token_account, 0)?;
+    let vesting_schedule = &mut ctx.accounts.vesting_schedule;
+    vesting_schedule.total_amount = received_amount;
+    vesting_schedule.amount_per_period = calculate_amount_per_period(received_amount, vesting_period, period_count);
+
+    emit!(VestingCreated::from_schedule(vesting_schedule.key(), vesting_schedule));
+
+    // 更新分发记录
+    let distributor = &mut ctx.accounts.distributor;
+    distributor.allocated_amount = distributor
+        .allocated_amount
+        .checked_add(amount)
+        .ok_or(VestingError::MathOverflow)?;
+    distributor.schedule_count = distributor
+        .schedule_count
+        .checked_add(1)
+        .ok_or(VestingError::MathOverflow)?;
+
+    // 提取当前已释放的代币
+    let claimable_amount = ctx.accounts.vesting_schedule.get_claimable_amount(clock.unix_timestamp)?;
+    if claimable_amount > 0 {
+        let distributor_key = ctx.accounts.distributor.key();
+        let leaf_index_bytes = leaf_index.to_le_bytes();
+        let seeds = &[
+            b"distributor_schedule".as_ref(),
+            distributor_key.as_ref(),
+            beneficiary.as_ref(),
+            ctx.accounts.vesting_schedule.mint.as_ref(),
+            leaf_index_bytes.as_ref(),
+            &[ctx.bumps.vesting_schedule],
+        ];
+        let signer_seeds = &[&seeds[..]];
+
+        let transfer_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.vault_token_account.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                to: ctx.accounts.beneficiary_token_account.to_account_info(),
+                authority: ctx.accounts.vesting_schedule.to_account_info(),
+            },
+            signer_seeds,
+        );
+        token_interface::transfer_checked(transfer_ctx, claimable_amount, ctx.accounts.mint.decimals)?;
+
+        ctx.accounts.vesting_schedule.claimed_amount = claimable_amount;
+
+        emit!(TokensClaimed {
+            vesting_schedule: ctx.accounts.vesting_schedule.key(),
+            beneficiary,
+            amount: claimable_amount,
+            claimed_amount: claimable_amount,
+            total_amount: ctx.accounts.vesting_schedule.total_amount,
+            progress: ctx.accounts.vesting_schedule.get_vesting_progress(clock.unix_timestamp),
+            timestamp: clock.unix_timestamp,
+        });
+    }
+
+    msg!(
+        "Merkle vesting schedule created for {}: {} tokens, claimed {}. Progress: {}%",
+        beneficiary,
+        received_amount,
+        claimable_amount,
+        ctx.accounts.vesting_schedule.get_vesting_progress(clock.unix_timestamp)
+    );
+    Ok(())
+}
+
+/// 从 Merkle 分发首次提取的账户验证
+#[derive(Accounts)]
+#[instruction(leaf: MerkleLeaf)]
+pub struct ClaimFromDistributor<'info> {
+    #[account(mut)]
+    pub beneficiary: Signer<'info>,
+
+    /// CHECK: 全局配置账户PDA，尚未初始化时视为未暂停，已初始化时在指令中读取暂停状态
+    #[account(seeds = [b"config"], bump)]
+    pub config: UncheckedAccount<'info>,
+
+    /// 代币mint
+    #[account(
+        constraint = mint.key() == distributor.mint @ VestingError::InvalidTokenMint
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Merkle 分发账户
+    #[account(
+        mut,
+        seeds = [b"distributor", distributor.creator.as_ref(), distributor.mint.as_ref(), distributor.distributor_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub distributor: Account<'info, MerkleDistributor>,
+
+    /// 分发托管代币账户
+    #[account(
+        mut,
+        seeds = [b"distributor_vault", distributor.key().as_ref()],
+        bump
+    )]
+    pub distributor_vault: InterfaceAccount<'info, TokenAccount>,
+
+    /// 叶子领取记录（PDA），已存在时创建失败，防止同一叶子重复领取
+    #[account(
+        init,
+        payer = beneficiary,
+        space = ClaimStatus::LEN,
+        seeds = [b"claim_status", distributor.key().as_ref(), leaf.leaf_index.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub claim_status: Account<'info, ClaimStatus>,
+
+    /// 释放计划账户（PDA，使用 Merkle 计划专用种子，与创建者直接创建的计划互不冲突）
+    #[account(
+        init,
+        payer = beneficiary,
+        space = VestingSchedule::LEN,
+        seeds = [
+            b"distributor_schedule",
+            distributor.key().as_ref(),
+            beneficiary.key().as_ref(),
+            mint.key().as_ref(),
+            leaf.leaf_index.to_le_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub vesting_schedule: Account<'info, VestingSchedule>,
+
+    /// 释放计划托管代币账户（PDA）
+    #[account(
+        init,
+        payer = beneficiary,
+        token::mint = mint,
+        token::authority = vesting_schedule,
+        token::token_program = token_program,
+        seeds = [b"vault", vesting_schedule.key().as_ref()],
+        bump
+    )]
+    pub vault_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// 受益人的代币账户
+    #[account(
+        mut,
+        constraint = beneficiary_token_account.mint == mint.key() @ VestingError::InvalidTokenMint,
+        constraint = beneficiary_token_account.owner == beneficiary.key() @ VestingError::Unauthorized
+    )]
+    pub beneficiary_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
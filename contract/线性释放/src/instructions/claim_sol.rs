@@ -0,0 +1,76 @@
+use anchor_lang::prelude::*;
+use crate::state::VestingSchedule;
+use crate::errors::VestingError;
+use crate::utils::is_claims_paused;
+use crate::events::TokensClaimed;
+
+/// 提取原生 SOL 释放计划中已释放的 lamports
+pub fn claim_sol(ctx: Context<ClaimSol>) -> Result<()> {
+    let clock = Clock::get()?;
+
+    // 全局暂停或计划冻结期间不能提取
+    require!(!is_claims_paused(&ctx.accounts.config)?, VestingError::ClaimsPaused);
+    require!(!ctx.accounts.vesting_schedule.frozen, VestingError::ScheduleFrozen);
+
+    // 计算可提取金额
+    let claimable_amount = ctx.accounts.vesting_schedule.get_claimable_amount(clock.unix_timestamp)?;
+    require!(claimable_amount > 0, VestingError::NothingToClaim);
+
+    // 释放计划PDA由本程序所有，直接划转 lamports
+    let schedule_info = ctx.accounts.vesting_schedule.to_account_info();
+    let beneficiary_info = ctx.accounts.beneficiary.to_account_info();
+    **schedule_info.try_borrow_mut_lamports()? = schedule_info
+        .lamports()
+        .checked_sub(claimable_amount)
+        .ok_or(VestingError::InsufficientBalance)?;
+    **beneficiary_info.try_borrow_mut_lamports()? = beneficiary_info
+        .lamports()
+        .checked_add(claimable_amount)
+        .ok_or(VestingError::MathOverflow)?;
+
+    // 更新已提取金额
+    ctx.accounts.vesting_schedule.claimed_amount = ctx.accounts.vesting_schedule.claimed_amount
+        .checked_add(claimable_amount)
+        .ok_or(VestingError::MathOverflow)?;
+
+    let progress = ctx.accounts.vesting_schedule.get_vesting_progress(clock.unix_timestamp);
+    emit!(TokensClaimed {
+        vesting_schedule: ctx.accounts.vesting_schedule.key(),
+        beneficiary: ctx.accounts.vesting_schedule.beneficiary,
+        amount: claimable_amount,
+        claimed_amount: ctx.accounts.vesting_schedule.claimed_amount,
+        total_amount: ctx.accounts.vesting_schedule.total_amount,
+        progress,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Claimed {} lamports. Progress: {}%", claimable_amount, progress);
+    Ok(())
+}
+
+/// 提取原生 SOL 的账户验证
+#[derive(Accounts)]
+pub struct ClaimSol<'info> {
+    #[account(mut)]
+    pub beneficiary: Signer<'info>,
+
+    /// CHECK: 全局配置账户PDA，尚未初始化时视为未暂停，已初始化时在指令中读取暂停状态
+    #[account(seeds = [b"config"], bump)]
+    pub config: UncheckedAccount<'info>,
+
+    /// 释放计划账户（托管 lamports）
+    #[account(
+        mut,
+        constraint = vesting_schedule.is_native() @ VestingError::InvalidTokenMint,
+        constraint = vesting_schedule.beneficiary == beneficiary.key() @ VestingError::Unauthorized,
+        seeds = [
+            vesting_schedule.seed_prefix(),
+            vesting_schedule.seed_authority().as_ref(),
+            vesting_schedule.initial_beneficiary.as_ref(),
+            vesting_schedule.mint.as_ref(),
+            vesting_schedule.schedule_id_seed().as_ref(),
+        ],
+        bump
+    )]
+    pub vesting_schedule: Account<'info, VestingSchedule>,
+}
@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+use crate::state::VestingSchedule;
+use crate::errors::VestingError;
+use crate::events::VestingClosed;
+
+/// 关闭已全部提取的原生 SOL 释放计划（仅创建者可用），租金退还给创建者
+pub fn close_sol_vesting(ctx: Context<CloseSolVesting>) -> Result<()> {
+    let vesting_schedule = &ctx.accounts.vesting_schedule;
+    let clock = Clock::get()?;
+
+    // 必须已全部提取
+    require!(
+        vesting_schedule.claimed_amount == vesting_schedule.total_amount,
+        VestingError::VestingNotFullyClaimed
+    );
+
+    emit!(VestingClosed {
+        vesting_schedule: vesting_schedule.key(),
+        creator: ctx.accounts.creator.key(),
+        total_amount: vesting_schedule.total_amount,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "SOL vesting schedule closed after {} lamports claimed, rent returned to {}",
+        vesting_schedule.total_amount,
+        ctx.accounts.creator.key()
+    );
+    Ok(())
+}
+
+/// 关闭原生 SOL 释放计划的账户验证（仅创建者可用）
+#[derive(Accounts)]
+pub struct CloseSolVesting<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    /// 释放计划账户
+    #[account(
+        mut,
+        constraint = vesting_schedule.is_native() @ VestingError::InvalidTokenMint,
+        constraint = vesting_schedule.creator == creator.key() @ VestingError::Unauthorized,
+        seeds = [vesting_schedule.seed_prefix(), vesting_schedule.seed_authority().as_ref(), vesting_schedule.initial_beneficiary.as_ref(), vesting_schedule.mint.as_ref(), vesting_schedule.schedule_id_seed().as_ref()],
+        bump,
+        close = creator
+    )]
+    pub vesting_schedule: Account<'info, VestingSchedule>,
+}
@@ -0,0 +1,114 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self, CloseAccount, Mint, TokenInterface, TokenAccount, TransferChecked};
+use crate::state::VestingSchedule;
+use crate::errors::VestingError;
+use crate::events::VestingClosed;
+
+/// 关闭已全部提取的释放计划（仅创建者可用）
+/// 关闭托管账户和释放计划账户，租金退还给创建者
+pub fn close_vesting(ctx: Context<CloseVesting>) -> Result<()> {
+    let vesting_schedule = &ctx.accounts.vesting_schedule;
+    let clock = Clock::get()?;
+
+    // 必须已全部提取
+    require!(
+        vesting_schedule.claimed_amount == vesting_schedule.total_amount,
+        VestingError::VestingNotFullyClaimed
+    );
+
+    // 生成PDA签名种子
+    let schedule_id_seed = vesting_schedule.schedule_id_seed();
+    let seeds = &[
+        vesting_schedule.seed_prefix(),
+        vesting_schedule.seed_authority().as_ref(),
+        vesting_schedule.initial_beneficiary.as_ref(),
+        vesting_schedule.mint.as_ref(),
+        schedule_id_seed.as_ref(),
+        &[ctx.bumps.vesting_schedule],
+    ];
+    let signer_seeds = &[&seeds[..]];
+
+    // 托管账户中的额外余额（如他人直接转入的代币）退还给创建者，否则无法关闭
+    let leftover_amount = ctx.accounts.vault_token_account.amount;
+    if leftover_amount > 0 {
+        let transfer_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.vault_token_account.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                to: ctx.accounts.creator_token_account.to_account_info(),
+                authority: ctx.accounts.vesting_schedule.to_account_info(),
+            },
+            signer_seeds,
+        );
+        token_interface::transfer_checked(transfer_ctx, leftover_amount, ctx.accounts.mint.decimals)?;
+    }
+
+    // 关闭托管账户，租金退还给创建者
+    let close_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        CloseAccount {
+            account: ctx.accounts.vault_token_account.to_account_info(),
+            destination: ctx.accounts.creator.to_account_info(),
+            authority: ctx.accounts.vesting_schedule.to_account_info(),
+        },
+        signer_seeds,
+    );
+    token_interface::close_account(close_ctx)?;
+
+    emit!(VestingClosed {
+        vesting_schedule: ctx.accounts.vesting_schedule.key(),
+        creator: ctx.accounts.creator.key(),
+        total_amount: ctx.accounts.vesting_schedule.total_amount,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Vesting schedule closed after {} tokens claimed, rent returned to {}",
+        ctx.accounts.vesting_schedule.total_amount,
+        ctx.accounts.creator.key()
+    );
+    Ok(())
+}
+
+/// 关闭释放计划的账户验证（仅创建者可用）
+#[derive(Accounts)]
+pub struct CloseVesting<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    /// 释放计划账户
+    #[account(
+        mut,
+        constraint = vesting_schedule.creator == creator.key() @ VestingError::Unauthorized,
+        seeds = [vesting_schedule.seed_prefix(), vesting_schedule.seed_authority().as_ref(), vesting_schedule.initial_beneficiary.as_ref(), vesting_schedule.mint.as_ref(), vesting_schedule.schedule_id_seed().as_ref()],
+        bump,
+        close = creator
+    )]
+    pub vesting_schedule: Account<'info, VestingSchedule>,
+
+    /// 代币mint
+    #[account(
+        constraint = mint.key() == vesting_schedule.mint @ VestingError::InvalidTokenMint
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// 托管代币账户
+    #[account(
+        mut,
+        constraint = vault_token_account.mint == vesting_schedule.mint @ VestingError::InvalidTokenMint,
+        seeds = [b"vault", vesting_schedule.key().as_ref()],
+        bump
+    )]
+    pub vault_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// 创建者的代币账户（用于接收托管账户中的额外余额）
+    #[account(
+        mut,
+        constraint = creator_token_account.mint == vesting_schedule.mint @ VestingError::InvalidTokenMint,
+        constraint = creator_token_account.owner == creator.key() @ VestingError::Unauthorized
+    )]
+    pub creator_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
@@ -0,0 +1,172 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self, Mint, TokenInterface, TokenAccount, TransferChecked};
+use crate::state::{VestingSchedule, VoterWeightRecord, BPS_DENOMINATOR};
+use crate::errors::VestingError;
+use crate::events::{TokensClaimed, ClaimCranked};
+use crate::utils::{is_claims_paused, sync_voter_weight};
+
+/// 代为提取已释放的代币（任何人可调用）
+/// 代币只发到受益人的关联代币账户；调用者传入自己的代币账户时按计划设置的比例获得小费
+pub fn crank_claim(ctx: Context<CrankClaim>) -> Result<()> {
+    let clock = Clock::get()?;
+
+    // 全局暂停或计划冻结期间不能提取
+    require!(!is_claims_paused(&ctx.accounts.config)?, VestingError::ClaimsPaused);
+    require!(!ctx.accounts.vesting_schedule.frozen, VestingError::ScheduleFrozen);
+
+    // 计算可提取金额
+    let claimable_amount = ctx.accounts.vesting_schedule.get_claimable_amount(clock.unix_timestamp)?;
+    require!(claimable_amount > 0, VestingError::NothingToClaim);
+
+    // 小费从本次提取金额中按比例扣除，未传入调用者代币账户时不收取
+    let tip_amount = if ctx.accounts.caller_token_account.is_some() {
+        (claimable_amount as u128 * ctx.accounts.vesting_schedule.crank_tip_bps as u128
+            / BPS_DENOMINATOR as u128) as u64
+    } else {
+        0
+    };
+    let beneficiary_amount = claimable_amount
+        .checked_sub(tip_amount)
+        .ok_or(VestingError::MathOverflow)?;
+
+    // 生成PDA签名种子
+    let schedule_id_seed = ctx.accounts.vesting_schedule.schedule_id_seed();
+    let seeds = &[
+        ctx.accounts.vesting_schedule.seed_prefix(),
+        ctx.accounts.vesting_schedule.seed_authority().as_ref(),
+        ctx.accounts.vesting_schedule.initial_beneficiary.as_ref(),
+        ctx.accounts.vesting_schedule.mint.as_ref(),
+        schedule_id_seed.as_ref(),
+        &[ctx.bumps.vesting_schedule],
+    ];
+    let signer_seeds = &[&seeds[..]];
+
+    if beneficiary_amount > 0 {
+        // 从托管账户转移代币到受益人的关联代币账户
+        let transfer_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.vault_token_account.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                to: ctx.accounts.beneficiary_token_account.to_account_info(),
+                authority: ctx.accounts.vesting_schedule.to_account_info(),
+            },
+            signer_seeds,
+        );
+        token_interface::transfer_checked(transfer_ctx, beneficiary_amount, ctx.accounts.mint.decimals)?;
+    }
+
+    if let Some(caller_token_account) = &ctx.accounts.caller_token_account {
+        if tip_amount > 0 {
+            // 支付小费给调用者
+            let transfer_ctx = CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.vault_token_account.to_account_info(),
+                    mint: ctx.accounts.mint.to_account_info(),
+                    to: caller_token_account.to_account_info(),
+                    authority: ctx.accounts.vesting_schedule.to_account_info(),
+                },
+                signer_seeds,
+            );
+            token_interface::transfer_checked(transfer_ctx, tip_amount, ctx.accounts.mint.decimals)?;
+        }
+    }
+
+    // 更新已提取金额（小费计入已提取）
+    ctx.accounts.vesting_schedule.claimed_amount = ctx.accounts.vesting_schedule.claimed_amount
+        .checked_add(claimable_amount)
+        .ok_or(VestingError::MathOverflow)?;
+
+    // 同步治理投票权重
+    let accounts = &mut *ctx.accounts;
+    let voting_weight = accounts.vesting_schedule.get_voting_weight();
+    sync_voter_weight(&mut accounts.vesting_schedule, accounts.voter_weight_record.as_mut(), voting_weight)?;
+
+    let progress = ctx.accounts.vesting_schedule.get_vesting_progress(clock.unix_timestamp);
+    emit!(TokensClaimed {
+        vesting_schedule: ctx.accounts.vesting_schedule.key(),
+        beneficiary: ctx.accounts.vesting_schedule.beneficiary,
+        amount: claimable_amount,
+        claimed_amount: ctx.accounts.vesting_schedule.claimed_amount,
+        total_amount: ctx.accounts.vesting_schedule.total_amount,
+        progress,
+        timestamp: clock.unix_timestamp,
+    });
+    emit!(ClaimCranked {
+        vesting_schedule: ctx.accounts.vesting_schedule.key(),
+        caller: ctx.accounts.caller.key(),
+        beneficiary_amount,
+        tip_amount,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Cranked claim: {} tokens to beneficiary, {} tip to caller. Progress: {}%",
+        beneficiary_amount,
+        tip_amount,
+        progress
+    );
+    Ok(())
+}
+
+/// 代为提取的账户验证（调用者无需是受益人）
+#[derive(Accounts)]
+pub struct CrankClaim<'info> {
+    pub caller: Signer<'info>,
+
+    /// CHECK: 全局配置账户PDA，尚未初始化时视为未暂停，已初始化时在指令中读取暂停状态
+    #[account(seeds = [b"config"], bump)]
+    pub config: UncheckedAccount<'info>,
+
+    /// 释放计划账户
+    #[account(
+        mut,
+        seeds = [
+            vesting_schedule.seed_prefix(),
+            vesting_schedule.seed_authority().as_ref(),
+            vesting_schedule.initial_beneficiary.as_ref(),
+            vesting_schedule.mint.as_ref(),
+            vesting_schedule.schedule_id_seed().as_ref(),
+        ],
+        bump
+    )]
+    pub vesting_schedule: Account<'info, VestingSchedule>,
+
+    /// 代币mint
+    #[account(
+        constraint = mint.key() == vesting_schedule.mint @ VestingError::InvalidTokenMint
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// 托管代币账户
+    #[account(
+        mut,
+        constraint = vault_token_account.mint == vesting_schedule.mint @ VestingError::InvalidTokenMint,
+        constraint = vault_token_account.owner == vesting_schedule.key() @ VestingError::Unauthorized
+    )]
+    pub vault_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// 受益人的关联代币账户（只能发到该账户）
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = vesting_schedule.beneficiary,
+        associated_token::token_program = token_program
+    )]
+    pub beneficiary_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// 调用者接收小费的代币账户（可选）
+    #[account(
+        mut,
+        constraint = caller_token_account.mint == vesting_schedule.mint @ VestingError::InvalidTokenMint,
+        constraint = caller_token_account.owner == caller.key() @ VestingError::Unauthorized
+    )]
+    pub caller_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// 已委托治理投票权时传入对应的投票权重记录，用于同步权重
+    #[account(mut)]
+    pub voter_weight_record: Option<Account<'info, VoterWeightRecord>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
@@ -0,0 +1,116 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self, Mint, TokenInterface, TokenAccount, TransferChecked};
+use crate::state::MerkleDistributor;
+use crate::errors::VestingError;
+use crate::utils::{check_sufficient_balance, get_received_amount};
+use crate::events::DistributorCreated;
+
+/// 创建 Merkle 分发（发布 Merkle 根并一次性注资）
+pub fn create_merkle_distributor(
+    ctx: Context<CreateMerkleDistributor>,
+    distributor_id: u64,
+    root: [u8; 32],
+    total_amount: u64,
+    claim_deadline: i64,
+) -> Result<()> {
+    let distributor = &mut ctx.accounts.distributor;
+    let clock = Clock::get()?;
+
+    require!(total_amount > 0, VestingError::InvalidAmount);
+    // 必须设置领取截止时间，截止前剩余代币只能由受益人领取
+    require!(claim_deadline > clock.unix_timestamp, VestingError::InvalidClaimDeadline);
+
+    // 检查创建者代币账户余额
+    check_sufficient_balance(&ctx.accounts.creator_token_account, total_amount)?;
+
+    // 初始化分发账户
+    distributor.creator = ctx.accounts.creator.key();
+    distributor.mint = ctx.accounts.mint.key();
+    distributor.distributor_id = distributor_id;
+    distributor.root = root;
+    distributor.total_amount = total_amount;
+    distributor.allocated_amount = 0;
+    distributor.schedule_count = 0;
+    distributor.claim_deadline = claim_deadline;
+    distributor.created_at = clock.unix_timestamp;
+
+    // 一次性将代币转入分发托管账户
+    let transfer_ctx = CpiContext::new(
+        ctx.accounts.token_program.to_account_info(),
+        TransferChecked {
+            from: ctx.accounts.creator_token_account.to_account_info(),
+            mint: ctx.accounts.mint.to_account_info(),
+            to: ctx.accounts.distributor_vault.to_account_info(),
+            authority: ctx.accounts.creator.to_account_info(),
+        },
+    );
+    token_interface::transfer_checked(transfer_ctx, total_amount, ctx.accounts.mint.decimals)?;
+
+    // 按托管账户实际到账金额记录（Token-2022 转账手续费）
+    let received_amount = get_received_amount(&mut ctx.accounts.distributor_vault, 0)?;
+    let distributor = &mut ctx.accounts.distributor;
+    distributor.total_amount = received_amount;
+
+    emit!(DistributorCreated {
+        distributor: distributor.key(),
+        creator: distributor.creator,
+        mint: distributor.mint,
+        distributor_id,
+        root,
+        total_amount: received_amount,
+        claim_deadline,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Merkle distributor #{} created: {} tokens",
+        distributor_id,
+        received_amount
+    );
+    Ok(())
+}
+
+/// 创建 Merkle 分发的账户验证
+#[derive(Accounts)]
+#[instruction(distributor_id: u64)]
+pub struct CreateMerkleDistributor<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    /// 代币mint
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// 创建者的代币账户
+    #[account(
+        mut,
+        constraint = creator_token_account.mint == mint.key() @ VestingError::InvalidTokenMint,
+        constraint = creator_token_account.owner == creator.key() @ VestingError::Unauthorized
+    )]
+    pub creator_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// Merkle 分发账户（PDA）
+    #[account(
+        init,
+        payer = creator,
+        space = MerkleDistributor::LEN,
+        seeds = [b"distributor", creator.key().as_ref(), mint.key().as_ref(), distributor_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub distributor: Account<'info, MerkleDistributor>,
+
+    /// 分发托管代币账户（PDA）
+    #[account(
+        init,
+        payer = creator,
+        token::mint = mint,
+        token::authority = distributor,
+        token::token_program = token_program,
+        seeds = [b"distributor_vault", distributor.key().as_ref()],
+        bump
+    )]
+    pub distributor_vault: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
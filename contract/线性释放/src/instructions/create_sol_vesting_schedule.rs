@@ -0,0 +1,114 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Transfer};
+use crate::state::{VestingSchedule, VestingParams, NATIVE_SOL_MINT};
+use crate::errors::VestingError;
+use crate::events::VestingCreated;
+use crate::utils::{validate_cliff_duration, schedule_id_seed, calculate_amount_per_period};
+use crate::instructions::create_vesting_schedule::validate_vesting_terms;
+
+/// 创建原生 SOL 释放计划，lamports 直接托管在释放计划PDA中，无需包装为 wSOL
+/// 原生 SOL 计划不支持代为提取，`crank_tip_bps` 必须为 0
+pub fn create_sol_vesting_schedule(
+    ctx: Context<CreateSolVestingSchedule>,
+    schedule_id: u64,
+    params: VestingParams,
+) -> Result<()> {
+    let vesting_schedule = &mut ctx.accounts.vesting_schedule;
+    let clock = Clock::get()?;
+
+    // 验证释放条款
+    let period_count = validate_vesting_terms(&params, clock.unix_timestamp)?;
+    require!(params.crank_tip_bps == 0, VestingError::InvalidCrankTip);
+
+    let VestingParams {
+        total_amount,
+        start_time,
+        vesting_period,
+        cliff_duration,
+        revocable,
+        creator_can_transfer,
+        milestones,
+        ..
+    } = params;
+
+    // 检查创建者 SOL 余额
+    require!(
+        ctx.accounts.creator.lamports() >= total_amount,
+        VestingError::InsufficientBalance
+    );
+
+    // 初始化释放计划
+    vesting_schedule.creator = ctx.accounts.creator.key();
+    vesting_schedule.beneficiary = ctx.accounts.beneficiary.key();
+    vesting_schedule.initial_beneficiary = ctx.accounts.beneficiary.key();
+    vesting_schedule.mint = NATIVE_SOL_MINT;
+    vesting_schedule.schedule_id = schedule_id;
+    vesting_schedule.total_amount = total_amount;
+    vesting_schedule.claimed_amount = 0;
+    vesting_schedule.start_time = start_time;
+    vesting_schedule.cliff_duration = cliff_duration;
+    vesting_schedule.vesting_period = vesting_period;
+    vesting_schedule.period_count = period_count;
+    vesting_schedule.amount_per_period = calculate_amount_per_period(total_amount, vesting_period, period_count);
+    vesting_schedule.milestones = milestones;
+    vesting_schedule.revocable = revocable;
+    vesting_schedule.creator_can_transfer = creator_can_transfer;
+    vesting_schedule.frozen = false;
+    vesting_schedule.crank_tip_bps = 0;
+    vesting_schedule.voter_weight_record = Pubkey::default();
+    vesting_schedule.delegated_weight = 0;
+    vesting_schedule.distributor = Pubkey::default();
+    vesting_schedule.created_at = clock.unix_timestamp;
+
+    // 锁定期不能超过总释放时长（按月/按年按日历计算）
+    validate_cliff_duration(cliff_duration, vesting_schedule.get_total_duration())?;
+
+    // 转移 lamports 到释放计划PDA（租金之外的余额即为托管金额）
+    let transfer_ctx = CpiContext::new(
+        ctx.accounts.system_program.to_account_info(),
+        Transfer {
+            from: ctx.accounts.creator.to_account_info(),
+            to: ctx.accounts.vesting_schedule.to_account_info(),
+        },
+    );
+    system_program::transfer(transfer_ctx, total_amount)?;
+
+    emit!(VestingCreated::from_schedule(
+        ctx.accounts.vesting_schedule.key(),
+        &ctx.accounts.vesting_schedule
+    ));
+
+    msg!(
+        "SOL vesting schedule #{} created: {} lamports over {} periods starting at {}, cliff {} seconds, revocable: {}",
+        schedule_id,
+        total_amount,
+        period_count,
+        start_time,
+        cliff_duration,
+        revocable
+    );
+    Ok(())
+}
+
+/// 创建原生 SOL 释放计划的账户验证
+#[derive(Accounts)]
+#[instruction(schedule_id: u64)]
+pub struct CreateSolVestingSchedule<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    /// CHECK: 受益人账户，不需要签名
+    pub beneficiary: UncheckedAccount<'info>,
+
+    /// 释放计划账户（PDA），同时托管 lamports
+    #[account(
+        init,
+        payer = creator,
+        space = VestingSchedule::LEN,
+        seeds = [b"vesting", creator.key().as_ref(), beneficiary.key().as_ref(), NATIVE_SOL_MINT.as_ref(), schedule_id_seed(schedule_id).as_ref()],
+        bump
+    )]
+    pub vesting_schedule: Account<'info, VestingSchedule>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,140 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self, Mint, TokenInterface, TokenAccount, TransferChecked};
+use crate::state::{VestingCampaign, VestingParams};
+use crate::errors::VestingError;
+use crate::utils::{validate_cliff_duration, check_sufficient_balance, get_received_amount};
+use crate::instructions::create_vesting_schedule::validate_vesting_terms;
+use crate::events::CampaignCreated;
+
+/// 创建批量释放活动（一次性注资，之后按共享条款分批添加受益人）
+/// 活动创建的计划不支持代为提取小费，`crank_tip_bps` 必须为 0
+pub fn create_vesting_campaign(
+    ctx: Context<CreateVestingCampaign>,
+    campaign_id: u64,
+    params: VestingParams,
+) -> Result<()> {
+    let vesting_campaign = &mut ctx.accounts.vesting_campaign;
+    let clock = Clock::get()?;
+
+    // 验证共享释放条款
+    let period_count = validate_vesting_terms(&params, clock.unix_timestamp)?;
+    require!(params.crank_tip_bps == 0, VestingError::InvalidCrankTip);
+
+    let VestingParams {
+        total_amount,
+        start_time,
+        vesting_period,
+        cliff_duration,
+        revocable,
+        creator_can_transfer,
+        milestones,
+        ..
+    } = params;
+
+    // 检查创建者代币账户余额
+    check_sufficient_balance(&ctx.accounts.creator_token_account, total_amount)?;
+
+    // 初始化活动
+    vesting_campaign.creator = ctx.accounts.creator.key();
+    vesting_campaign.mint = ctx.accounts.mint.key();
+    vesting_campaign.campaign_id = campaign_id;
+    vesting_campaign.total_amount = total_amount;
+    vesting_campaign.allocated_amount = 0;
+    vesting_campaign.schedule_count = 0;
+    vesting_campaign.start_time = start_time;
+    vesting_campaign.cliff_duration = cliff_duration;
+    vesting_campaign.vesting_period = vesting_period;
+    vesting_campaign.period_count = period_count;
+    vesting_campaign.milestones = milestones;
+    vesting_campaign.revocable = revocable;
+    vesting_campaign.creator_can_transfer = creator_can_transfer;
+    vesting_campaign.created_at = clock.unix_timestamp;
+
+    // 锁定期不能超过总释放时长
+    let template = vesting_campaign.build_schedule(Pubkey::default(), 0, total_amount, clock.unix_timestamp);
+    validate_cliff_duration(cliff_duration, template.get_total_duration())?;
+
+    // 一次性将代币转入活动托管账户
+    let transfer_ctx = CpiContext::new(
+        ctx.accounts.token_program.to_account_info(),
+        TransferChecked {
+            from: ctx.accounts.creator_token_account.to_account_info(),
+            mint: ctx.accounts.mint.to_account_info(),
+            to: ctx.accounts.campaign_vault.to_account_info(),
+            authority: ctx.accounts.creator.to_account_info(),
+        },
+    );
+    token_interface::transfer_checked(transfer_ctx, total_amount, ctx.accounts.mint.decimals)?;
+
+    // 按托管账户实际到账金额记录（Token-2022 转账手续费）
+    let received_amount = get_received_amount(&mut ctx.accounts.campaign_vault, 0)?;
+    let vesting_campaign = &mut ctx.accounts.vesting_campaign;
+    vesting_campaign.total_amount = received_amount;
+
+    emit!(CampaignCreated {
+        vesting_campaign: vesting_campaign.key(),
+        creator: vesting_campaign.creator,
+        mint: vesting_campaign.mint,
+        campaign_id,
+        total_amount: received_amount,
+        start_time,
+        cliff_duration,
+        vesting_period,
+        period_count,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Vesting campaign #{} created: {} tokens, {} periods starting at {}",
+        campaign_id,
+        received_amount,
+        period_count,
+        start_time
+    );
+    Ok(())
+}
+
+/// 创建批量释放活动的账户验证
+#[derive(Accounts)]
+#[instruction(campaign_id: u64)]
+pub struct CreateVestingCampaign<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    /// 代币mint
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// 创建者的代币账户
+    #[account(
+        mut,
+        constraint = creator_token_account.mint == mint.key() @ VestingError::InvalidTokenMint,
+        constraint = creator_token_account.owner == creator.key() @ VestingError::Unauthorized
+    )]
+    pub creator_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// 批量释放活动账户（PDA）
+    #[account(
+        init,
+        payer = creator,
+        space = VestingCampaign::LEN,
+        seeds = [b"campaign", creator.key().as_ref(), mint.key().as_ref(), campaign_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub vesting_campaign: Account<'info, VestingCampaign>,
+
+    /// 活动托管代币账户（PDA）
+    #[account(
+        init,
+        payer = creator,
+        token::mint = mint,
+        token::authority = vesting_campaign,
+        token::token_program = token_program,
+        seeds = [b"campaign_vault", vesting_campaign.key().as_ref()],
+        bump
+    )]
+    pub campaign_vault: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
@@ -1,8 +1,9 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Token, TokenAccount, Transfer};
-use crate::state::{VestingSchedule, VestingPeriod};
+use anchor_spl::token_interface::{self, Mint, TokenInterface, TokenAccount, TransferChecked};
+use crate::state::{VestingSchedule, VestingPeriod, VestingParams, MAX_CRANK_TIP_BPS};
 use crate::errors::VestingError;
-use crate::utils::{validate_vesting_params_v2, check_sufficient_balance};
+use crate::events::VestingCreated;
+use crate::utils::{validate_vesting_params_v2, validate_cliff_duration, check_sufficient_balance, schedule_id_seed, validate_milestones, calculate_amount_per_period, get_received_amount, MAX_CALENDAR_MONTHS};
 
 
 
@@ -11,86 +12,143 @@ pub fn validate_vesting_period(vesting_period: VestingPeriod) -> Result<()> {
         VestingPeriod::Daily 
         | VestingPeriod::Monthly 
         | VestingPeriod::Yearly 
-        | VestingPeriod::Linear => Ok(()),
+        | VestingPeriod::Linear
+        | VestingPeriod::Custom => Ok(()),
         // 如果未来扩展了未知枚举值
         _ => Err(VestingError::InvalidVestingPeriod.into()),
     }
 }
 
+/// 验证释放条款，返回实际周期数（自定义解锁表为里程碑数量）
+pub fn validate_vesting_terms(params: &VestingParams, current_time: i64) -> Result<u32> {
+    validate_vesting_period(params.vesting_period)?;
+
+    // 自定义解锁表：周期数为里程碑数量；其他模式不能传入解锁表
+    let period_count = if params.vesting_period == VestingPeriod::Custom {
+        validate_milestones(&params.milestones, params.start_time)?;
+        params.milestones.len() as u32
+    } else {
+        require!(params.milestones.is_empty(), VestingError::InvalidMilestones);
+        params.period_count
+    };
+
+    // 验证参数
+    validate_vesting_params_v2(params.total_amount, params.start_time, period_count, current_time)?;
+
+    // 按日历释放时限制总月数
+    if let Some(months) = params.vesting_period.to_months() {
+        require!(
+            months * period_count as i64 <= MAX_CALENDAR_MONTHS,
+            VestingError::InvalidPeriodCount
+        );
+    }
+
+    Ok(period_count)
+}
+
 /// 创建释放计划
 pub fn create_vesting_schedule(
     ctx: Context<CreateVestingSchedule>,
-    total_amount: u64,
-    start_time: i64,
-    vesting_period: VestingPeriod,
-    period_count: u32,
+    schedule_id: u64,
+    params: VestingParams,
 ) -> Result<()> {
-
-    validate_vesting_period(vesting_period)?;
-    
     let vesting_schedule = &mut ctx.accounts.vesting_schedule;
     let clock = Clock::get()?;
 
-    // 验证参数
-    validate_vesting_params_v2(total_amount, start_time, period_count, clock.unix_timestamp)?;
+    // 验证释放条款
+    let period_count = validate_vesting_terms(&params, clock.unix_timestamp)?;
+
+    let VestingParams {
+        total_amount,
+        start_time,
+        vesting_period,
+        cliff_duration,
+        revocable,
+        creator_can_transfer,
+        milestones,
+        crank_tip_bps,
+        ..
+    } = params;
+
+    require!(crank_tip_bps <= MAX_CRANK_TIP_BPS, VestingError::InvalidCrankTip);
 
     // 检查创建者代币账户余额
     check_sufficient_balance(&ctx.accounts.creator_token_account, total_amount)?;
 
     // 计算每个周期释放的金额
-    let amount_per_period = match vesting_period {
-        VestingPeriod::Linear => {
-            // 线性释放不需要每周期金额，设为0
-            0
-        },
-        _ => {
-            // 周期性释放：总金额除以周期数
-            total_amount / period_count as u64
-        }
-    };
+    let amount_per_period = calculate_amount_per_period(total_amount, vesting_period, period_count);
 
     // 初始化释放计划
     vesting_schedule.creator = ctx.accounts.creator.key();
     vesting_schedule.beneficiary = ctx.accounts.beneficiary.key();
+    vesting_schedule.initial_beneficiary = ctx.accounts.beneficiary.key();
     vesting_schedule.mint = ctx.accounts.mint.key();
+    vesting_schedule.schedule_id = schedule_id;
     vesting_schedule.total_amount = total_amount;
     vesting_schedule.claimed_amount = 0;
     vesting_schedule.start_time = start_time;
+    vesting_schedule.cliff_duration = cliff_duration;
     vesting_schedule.vesting_period = vesting_period;
     vesting_schedule.period_count = period_count;
     vesting_schedule.amount_per_period = amount_per_period;
+    vesting_schedule.milestones = milestones;
+    vesting_schedule.revocable = revocable;
+    vesting_schedule.creator_can_transfer = creator_can_transfer;
+    vesting_schedule.frozen = false;
+    vesting_schedule.crank_tip_bps = crank_tip_bps;
+    vesting_schedule.voter_weight_record = Pubkey::default();
+    vesting_schedule.delegated_weight = 0;
+    vesting_schedule.distributor = Pubkey::default();
     vesting_schedule.created_at = clock.unix_timestamp;
 
+    // 锁定期不能超过总释放时长（按月/按年按日历计算）
+    validate_cliff_duration(cliff_duration, vesting_schedule.get_total_duration())?;
+
     // 转移代币到托管账户
+    let vault_balance_before = ctx.accounts.vault_token_account.amount;
     let transfer_ctx = CpiContext::new(
         ctx.accounts.token_program.to_account_info(),
-        Transfer {
+        TransferChecked {
             from: ctx.accounts.creator_token_account.to_account_info(),
+            mint: ctx.accounts.mint.to_account_info(),
             to: ctx.accounts.vault_token_account.to_account_info(),
             authority: ctx.accounts.creator.to_account_info(),
         },
     );
-    token::transfer(transfer_ctx, total_amount)?;
+    token_interface::transfer_checked(transfer_ctx, total_amount, ctx.accounts.mint.decimals)?;
+
+    // 按托管账户实际到账金额记录（Token-2022 转账手续费会使到账金额少于转出金额）
+    let received_amount = get_received_amount(&mut ctx.accounts.vault_token_account, vault_balance_before)?;
+    let vesting_schedule = &mut ctx.accounts.vesting_schedule;
+    vesting_schedule.total_amount = received_amount;
+    vesting_schedule.amount_per_period = calculate_amount_per_period(received_amount, vesting_period, period_count);
+
+    emit!(VestingCreated::from_schedule(vesting_schedule.key(), vesting_schedule));
 
     let period_name = match vesting_period {
         VestingPeriod::Daily => "days",
         VestingPeriod::Monthly => "months",
         VestingPeriod::Yearly => "years",
         VestingPeriod::Linear => "linear",
+        VestingPeriod::Custom => "milestones",
     };
 
     msg!(
-        "Vesting schedule created: {} tokens over {} {} starting at {}",
-        total_amount,
+        "Vesting schedule #{} created: {} tokens over {} {} starting at {}, cliff {} seconds, revocable: {}",
+        schedule_id,
+        received_amount,
         period_count,
         period_name,
-        start_time
+        start_time,
+        cliff_duration,
+        revocable
     );
     Ok(())
 }
 
 /// 创建释放计划的账户验证
 #[derive(Accounts)]
+#[instruction(schedule_id: u64)]
 pub struct CreateVestingSchedule<'info> {
     #[account(mut)]
     pub creator: Signer<'info>,
@@ -99,7 +157,7 @@ pub struct CreateVestingSchedule<'info> {
     pub beneficiary: UncheckedAccount<'info>,
     
     /// 代币mint
-    pub mint: Account<'info, anchor_spl::token::Mint>,
+    pub mint: InterfaceAccount<'info, Mint>,
     
     /// 创建者的代币账户
     #[account(
@@ -107,7 +165,7 @@ pub struct CreateVestingSchedule<'info> {
         constraint = creator_token_account.mint == mint.key() @ VestingError::InvalidTokenMint,
         constraint = creator_token_account.owner == creator.key() @ VestingError::Unauthorized
     )]
-    pub creator_token_account: Account<'info, TokenAccount>,
+    pub creator_token_account: InterfaceAccount<'info, TokenAccount>,
     
     /// 托管代币账户（PDA）
     #[account(
@@ -116,19 +174,19 @@ pub struct CreateVestingSchedule<'info> {
         seeds = [b"vault", vesting_schedule.key().as_ref()],
         bump
     )]
-    pub vault_token_account: Account<'info, TokenAccount>,
+    pub vault_token_account: InterfaceAccount<'info, TokenAccount>,
     
     /// 释放计划账户（PDA）
     #[account(
         init,
         payer = creator,
         space = VestingSchedule::LEN,
-        seeds = [b"vesting", creator.key().as_ref(), beneficiary.key().as_ref(), mint.key().as_ref()],
+        seeds = [b"vesting", creator.key().as_ref(), beneficiary.key().as_ref(), mint.key().as_ref(), schedule_id_seed(schedule_id).as_ref()],
         bump
     )]
     pub vesting_schedule: Account<'info, VestingSchedule>,
     
-    pub token_program: Program<'info, Token>,
+    pub token_program: Interface<'info, TokenInterface>,
     pub system_program: Program<'info, System>,
     pub rent: Sysvar<'info, Rent>,
 }
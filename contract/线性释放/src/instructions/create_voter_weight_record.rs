@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+use crate::state::VoterWeightRecord;
+
+/// 为委托人创建投票权重记录（任何人可调用并支付租金）
+pub fn create_voter_weight_record(ctx: Context<CreateVoterWeightRecord>) -> Result<()> {
+    let voter_weight_record = &mut ctx.accounts.voter_weight_record;
+    voter_weight_record.realm = ctx.accounts.realm.key();
+    voter_weight_record.governing_token_mint = ctx.accounts.mint.key();
+    voter_weight_record.governing_token_owner = ctx.accounts.delegate.key();
+    voter_weight_record.voter_weight = 0;
+    voter_weight_record.voter_weight_expiry = None;
+    voter_weight_record.weight_action = None;
+    voter_weight_record.weight_action_target = None;
+    voter_weight_record.reserved = [0u8; 8];
+
+    msg!(
+        "Voter weight record created for {} in realm {}",
+        voter_weight_record.governing_token_owner,
+        voter_weight_record.realm
+    );
+    Ok(())
+}
+
+/// 创建投票权重记录的账户验证
+#[derive(Accounts)]
+pub struct CreateVoterWeightRecord<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: 治理域（Realm）账户，仅记录地址
+    pub realm: UncheckedAccount<'info>,
+
+    /// 治理代币mint
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// CHECK: 委托人钱包，不需要签名
+    pub delegate: UncheckedAccount<'info>,
+
+    /// 投票权重记录（PDA）
+    #[account(
+        init,
+        payer = payer,
+        space = VoterWeightRecord::LEN,
+        seeds = [b"voter-weight-record", realm.key().as_ref(), mint.key().as_ref(), delegate.key().as_ref()],
+        bump
+    )]
+    pub voter_weight_record: Account<'info, VoterWeightRecord>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -7,7 +7,7 @@ pub fn get_claimable_amount(ctx: Context<GetClaimableAmount>) -> Result<u64> {
     let clock = Clock::get()?;
     
     let claimable_amount = vesting_schedule.get_claimable_amount(clock.unix_timestamp)?;
-    msg!("Claimable amount: {}", claimable_amount);
+    msg!("Schedule #{} claimable amount: {}", vesting_schedule.schedule_id, claimable_amount);
     Ok(claimable_amount)
 }
 
@@ -1,25 +1,40 @@
 use anchor_lang::prelude::*;
-use crate::state::{VestingSchedule, VestingPeriod};
+use crate::state::{VestingSchedule, VestingPeriod, Milestone};
+use crate::utils::is_claims_paused;
 
 /// 释放计划信息结构体
 #[derive(AnchorSerialize, AnchorDeserialize, Clone)]
 pub struct VestingInfo {
     pub creator: Pubkey,
     pub beneficiary: Pubkey,
+    pub initial_beneficiary: Pubkey,
     pub mint: Pubkey,
+    pub schedule_id: u64,
     pub total_amount: u64,
     pub claimed_amount: u64,
     pub claimable_amount: u64,
     pub locked_amount: u64,
     pub start_time: i64,
+    pub cliff_duration: i64,
+    pub cliff_end_time: i64,
+    pub is_cliff_passed: bool,
     pub vesting_period: VestingPeriod,
     pub period_count: u32,
     pub amount_per_period: u64,
+    pub milestones: Vec<Milestone>,
+    pub unlocked_bps: u64,
+    pub revocable: bool,
+    pub creator_can_transfer: bool,
     pub completed_periods: u32,
     pub created_at: i64,
     pub progress: u8,
     pub is_fully_vested: bool,
     pub next_release_time: Option<i64>,
+    pub is_paused: bool,
+    pub is_frozen: bool,
+    pub crank_tip_bps: u16,
+    pub voter_weight_record: Pubkey,
+    pub delegated_weight: u64,
 }
 
 /// 查询释放计划详细信息
@@ -33,24 +48,39 @@ pub fn get_vesting_info(ctx: Context<GetVestingInfo>) -> Result<VestingInfo> {
     let is_fully_vested = vesting_schedule.is_fully_vested(clock.unix_timestamp);
     let completed_periods = vesting_schedule.get_completed_periods(clock.unix_timestamp);
     let next_release_time = vesting_schedule.get_next_release_time(clock.unix_timestamp);
+    let is_cliff_passed = vesting_schedule.is_cliff_passed(clock.unix_timestamp);
 
     let info = VestingInfo {
         creator: vesting_schedule.creator,
         beneficiary: vesting_schedule.beneficiary,
+        initial_beneficiary: vesting_schedule.initial_beneficiary,
         mint: vesting_schedule.mint,
+        schedule_id: vesting_schedule.schedule_id,
         total_amount: vesting_schedule.total_amount,
         claimed_amount: vesting_schedule.claimed_amount,
         claimable_amount,
         locked_amount,
         start_time: vesting_schedule.start_time,
+        cliff_duration: vesting_schedule.cliff_duration,
+        cliff_end_time: vesting_schedule.get_cliff_end_time(),
+        is_cliff_passed,
         vesting_period: vesting_schedule.vesting_period,
         period_count: vesting_schedule.period_count,
         amount_per_period: vesting_schedule.amount_per_period,
+        milestones: vesting_schedule.milestones.clone(),
+        unlocked_bps: vesting_schedule.get_unlocked_bps(clock.unix_timestamp),
+        revocable: vesting_schedule.revocable,
+        creator_can_transfer: vesting_schedule.creator_can_transfer,
         completed_periods,
         created_at: vesting_schedule.created_at,
         progress,
         is_fully_vested,
         next_release_time,
+        is_paused: is_claims_paused(&ctx.accounts.config)?,
+        is_frozen: vesting_schedule.frozen,
+        crank_tip_bps: vesting_schedule.crank_tip_bps,
+        voter_weight_record: vesting_schedule.voter_weight_record,
+        delegated_weight: vesting_schedule.delegated_weight,
     };
 
     let period_name = match vesting_schedule.vesting_period {
@@ -58,15 +88,18 @@ pub fn get_vesting_info(ctx: Context<GetVestingInfo>) -> Result<VestingInfo> {
         VestingPeriod::Monthly => "monthly",
         VestingPeriod::Yearly => "yearly",
         VestingPeriod::Linear => "linear",
+        VestingPeriod::Custom => "custom",
     };
 
     msg!(
-        "Vesting info: {} release, Progress {}%, Completed periods: {}/{}, Claimable: {}",
+        "Vesting info #{}: {} release, Progress {}%, Completed periods: {}/{}, Claimable: {}, Cliff passed: {}",
+        vesting_schedule.schedule_id,
         period_name,
         progress,
         completed_periods,
         vesting_schedule.period_count,
-        claimable_amount
+        claimable_amount,
+        is_cliff_passed
     );
     Ok(info)
 }
@@ -76,4 +109,8 @@ pub fn get_vesting_info(ctx: Context<GetVestingInfo>) -> Result<VestingInfo> {
 pub struct GetVestingInfo<'info> {
     /// 释放计划账户
     pub vesting_schedule: Account<'info, VestingSchedule>,
+
+    /// CHECK: 全局配置账户PDA，尚未初始化时视为未暂停，已初始化时在指令中读取暂停状态
+    #[account(seeds = [b"config"], bump)]
+    pub config: UncheckedAccount<'info>,
 }
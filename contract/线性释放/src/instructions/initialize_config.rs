@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+use crate::state::VestingConfig;
+use crate::errors::VestingError;
+use crate::program::VestingProject;
+use crate::events::ConfigInitialized;
+
+/// 初始化程序全局配置（仅程序升级权限账户可调用，并成为管理员）
+pub fn initialize_config(ctx: Context<InitializeConfig>) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    config.admin = ctx.accounts.admin.key();
+    config.paused = false;
+
+    emit!(ConfigInitialized {
+        admin: config.admin,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Vesting config initialized, admin: {}", config.admin);
+    Ok(())
+}
+
+/// 初始化全局配置的账户验证
+#[derive(Accounts)]
+pub struct InitializeConfig<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    /// 全局配置账户（PDA）
+    #[account(
+        init,
+        payer = admin,
+        space = VestingConfig::LEN,
+        seeds = [b"config"],
+        bump
+    )]
+    pub config: Account<'info, VestingConfig>,
+
+    /// 本程序账户
+    #[account(
+        constraint = program.programdata_address()? == Some(program_data.key()) @ VestingError::Unauthorized
+    )]
+    pub program: Program<'info, VestingProject>,
+
+    /// 本程序的 ProgramData 账户，调用者必须是升级权限账户
+    #[account(
+        constraint = program_data.upgrade_authority_address == Some(admin.key()) @ VestingError::Unauthorized
+    )]
+    pub program_data: Account<'info, ProgramData>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,165 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self, CloseAccount, Mint, TokenAccount, TokenInterface, TransferChecked};
+use crate::state::{LegacyVestingSchedule, VestingSchedule};
+use crate::errors::VestingError;
+
+/// 将旧版释放计划账户迁移为当前布局（任何人可调用，调用者补足扩容所需租金）
+/// 旧版计划的 PDA 地址即编号为 0 的计划地址，迁移后地址不变；
+/// 旧版托管账户（释放计划的关联代币账户）中的代币转入新的托管账户PDA，旧账户关闭后租金退还给创建者
+pub fn migrate_schedule(ctx: Context<MigrateSchedule>) -> Result<()> {
+    let schedule_info = ctx.accounts.vesting_schedule.to_account_info();
+
+    // 读取旧版布局，新布局账户长度更大，视为已迁移
+    let legacy = {
+        let data = schedule_info.try_borrow_data()?;
+        require!(data.len() == LegacyVestingSchedule::LEN, VestingError::ScheduleAlreadyMigrated);
+        require!(data[..8] == *VestingSchedule::DISCRIMINATOR, VestingError::InvalidScheduleAccount);
+        LegacyVestingSchedule::deserialize(&mut &data[8..])?
+    };
+
+    // 校验账户确实是旧版种子 ["vesting", creator, beneficiary, mint] 派生的PDA
+    let (expected_key, bump) = Pubkey::find_program_address(
+        &[
+            b"vesting",
+            legacy.creator.as_ref(),
+            legacy.beneficiary.as_ref(),
+            legacy.mint.as_ref(),
+        ],
+        &crate::ID,
+    );
+    require_keys_eq!(schedule_info.key(), expected_key, VestingError::InvalidScheduleAccount);
+    require_keys_eq!(ctx.accounts.mint.key(), legacy.mint, VestingError::InvalidTokenMint);
+    require_keys_eq!(ctx.accounts.creator.key(), legacy.creator, VestingError::Unauthorized);
+
+    // 新增字段按旧版行为填充：编号 0、无锁定期、创建者可取消
+    let vesting_schedule = VestingSchedule {
+        creator: legacy.creator,
+        beneficiary: legacy.beneficiary,
+        initial_beneficiary: legacy.beneficiary,
+        mint: legacy.mint,
+        schedule_id: 0,
+        total_amount: legacy.total_amount,
+        claimed_amount: legacy.claimed_amount,
+        start_time: legacy.start_time,
+        cliff_duration: 0,
+        vesting_period: legacy.vesting_period,
+        period_count: legacy.period_count,
+        amount_per_period: legacy.amount_per_period,
+        milestones: Vec::new(),
+        revocable: true,
+        creator_can_transfer: false,
+        frozen: false,
+        crank_tip_bps: 0,
+        voter_weight_record: Pubkey::default(),
+        delegated_weight: 0,
+        distributor: Pubkey::default(),
+        created_at: legacy.created_at,
+    };
+
+    // 补足扩容后的租金再扩容
+    let rent_lamports = Rent::get()?.minimum_balance(VestingSchedule::LEN);
+    let top_up = rent_lamports.saturating_sub(schedule_info.lamports());
+    if top_up > 0 {
+        anchor_lang::system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                anchor_lang::system_program::Transfer {
+                    from: ctx.accounts.payer.to_account_info(),
+                    to: schedule_info.clone(),
+                },
+            ),
+            top_up,
+        )?;
+    }
+    schedule_info.resize(VestingSchedule::LEN)?;
+    {
+        let mut data = schedule_info.try_borrow_mut_data()?;
+        vesting_schedule.try_serialize(&mut &mut data[..])?;
+    }
+
+    // 旧版托管账户中的代币转入新的托管账户PDA，并关闭旧账户
+    let seeds = &[
+        b"vesting",
+        legacy.creator.as_ref(),
+        legacy.beneficiary.as_ref(),
+        legacy.mint.as_ref(),
+        &[bump],
+    ];
+    let signer_seeds = &[&seeds[..]];
+
+    let legacy_balance = ctx.accounts.legacy_vault.amount;
+    if legacy_balance > 0 {
+        let transfer_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.legacy_vault.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                to: ctx.accounts.vault_token_account.to_account_info(),
+                authority: schedule_info.clone(),
+            },
+            signer_seeds,
+        );
+        token_interface::transfer_checked(transfer_ctx, legacy_balance, ctx.accounts.mint.decimals)?;
+    }
+
+    let close_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        CloseAccount {
+            account: ctx.accounts.legacy_vault.to_account_info(),
+            destination: ctx.accounts.creator.to_account_info(),
+            authority: schedule_info.clone(),
+        },
+        signer_seeds,
+    );
+    token_interface::close_account(close_ctx)?;
+
+    msg!(
+        "Vesting schedule {} migrated: creator {}, beneficiary {}",
+        schedule_info.key(),
+        legacy.creator,
+        legacy.beneficiary
+    );
+    Ok(())
+}
+
+/// 迁移旧版释放计划的账户验证
+#[derive(Accounts)]
+pub struct MigrateSchedule<'info> {
+    /// 支付扩容租金的账户
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: 旧版释放计划账户，布局与当前 VestingSchedule 不兼容，在指令中手动校验和解析
+    #[account(mut, owner = crate::ID @ VestingError::InvalidScheduleAccount)]
+    pub vesting_schedule: UncheckedAccount<'info>,
+
+    /// CHECK: 释放计划创建者，在指令中校验，接收旧托管账户关闭后的租金
+    #[account(mut)]
+    pub creator: UncheckedAccount<'info>,
+
+    /// 代币mint
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// 旧版托管代币账户（释放计划的关联代币账户）
+    #[account(
+        mut,
+        constraint = legacy_vault.mint == mint.key() @ VestingError::InvalidTokenMint,
+        constraint = legacy_vault.owner == vesting_schedule.key() @ VestingError::Unauthorized
+    )]
+    pub legacy_vault: InterfaceAccount<'info, TokenAccount>,
+
+    /// 新的托管代币账户（PDA）
+    #[account(
+        init,
+        payer = payer,
+        token::mint = mint,
+        token::authority = vesting_schedule,
+        token::token_program = token_program,
+        seeds = [b"vault", vesting_schedule.key().as_ref()],
+        bump
+    )]
+    pub vault_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
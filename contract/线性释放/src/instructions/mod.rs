@@ -1,11 +1,55 @@
 pub mod create_vesting_schedule;
 pub mod claim;
+pub mod crank_claim;
 pub mod get_claimable_amount;
 pub mod get_vesting_info;
 pub mod cancel_vesting;
+pub mod transfer_beneficiary;
+pub mod top_up_vesting;
+pub mod close_vesting;
+pub mod create_sol_vesting_schedule;
+pub mod claim_sol;
+pub mod cancel_sol_vesting;
+pub mod close_sol_vesting;
+pub mod create_voter_weight_record;
+pub mod update_voter_weight_record;
+pub mod set_vote_delegate;
+pub mod create_vesting_campaign;
+pub mod add_campaign_schedules;
+pub mod reclaim_campaign_remainder;
+pub mod create_merkle_distributor;
+pub mod claim_from_distributor;
+pub mod reclaim_distributor_remainder;
+pub mod initialize_config;
+pub mod set_paused;
+pub mod set_schedule_frozen;
+pub mod transfer_admin;
+pub mod migrate_schedule;
 
 pub use create_vesting_schedule::*;
 pub use claim::*;
+pub use crank_claim::*;
 pub use get_claimable_amount::*;
 pub use get_vesting_info::*;
 pub use cancel_vesting::*;
+pub use transfer_beneficiary::*;
+pub use top_up_vesting::*;
+pub use close_vesting::*;
+pub use create_sol_vesting_schedule::*;
+pub use claim_sol::*;
+pub use cancel_sol_vesting::*;
+pub use close_sol_vesting::*;
+pub use create_voter_weight_record::*;
+pub use update_voter_weight_record::*;
+pub use set_vote_delegate::*;
+pub use create_vesting_campaign::*;
+pub use add_campaign_schedules::*;
+pub use reclaim_campaign_remainder::*;
+pub use create_merkle_distributor::*;
+pub use claim_from_distributor::*;
+pub use reclaim_distributor_remainder::*;
+pub use initialize_config::*;
+pub use set_paused::*;
+pub use set_schedule_frozen::*;
+pub use transfer_admin::*;
+pub use migrate_schedule::*;
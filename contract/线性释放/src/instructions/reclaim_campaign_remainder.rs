@@ -0,0 +1,93 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self, Mint, TokenInterface, TokenAccount, TransferChecked};
+use crate::state::VestingCampaign;
+use crate::errors::VestingError;
+use crate::events::RemainderReclaimed;
+
+/// 取回批量释放活动中未分配的剩余代币（仅创建者可用），之后活动不能再添加受益人
+pub fn reclaim_campaign_remainder(ctx: Context<ReclaimCampaignRemainder>) -> Result<()> {
+    let remaining_amount = ctx.accounts.vesting_campaign.get_remaining_amount();
+    require!(remaining_amount > 0, VestingError::NothingToReclaim);
+
+    // 生成活动PDA签名种子
+    let campaign_id_bytes = ctx.accounts.vesting_campaign.campaign_id.to_le_bytes();
+    let seeds = &[
+        b"campaign",
+        ctx.accounts.vesting_campaign.creator.as_ref(),
+        ctx.accounts.vesting_campaign.mint.as_ref(),
+        campaign_id_bytes.as_ref(),
+        &[ctx.bumps.vesting_campaign],
+    ];
+    let signer_seeds = &[&seeds[..]];
+
+    // 将未分配的代币退还给创建者
+    let transfer_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        TransferChecked {
+            from: ctx.accounts.campaign_vault.to_account_info(),
+            mint: ctx.accounts.mint.to_account_info(),
+            to: ctx.accounts.creator_token_account.to_account_info(),
+            authority: ctx.accounts.vesting_campaign.to_account_info(),
+        },
+        signer_seeds,
+    );
+    token_interface::transfer_checked(transfer_ctx, remaining_amount, ctx.accounts.mint.decimals)?;
+
+    // 注资总额收缩为已分配金额
+    let vesting_campaign = &mut ctx.accounts.vesting_campaign;
+    vesting_campaign.total_amount = vesting_campaign.allocated_amount;
+
+    emit!(RemainderReclaimed {
+        source: vesting_campaign.key(),
+        amount: remaining_amount,
+        recipient: ctx.accounts.creator.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!(
+        "Campaign #{} reclaimed {} unallocated tokens",
+        vesting_campaign.campaign_id,
+        remaining_amount
+    );
+    Ok(())
+}
+
+/// 取回活动剩余代币的账户验证（仅创建者可用）
+#[derive(Accounts)]
+pub struct ReclaimCampaignRemainder<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    /// 批量释放活动账户
+    #[account(
+        mut,
+        constraint = vesting_campaign.creator == creator.key() @ VestingError::Unauthorized,
+        seeds = [b"campaign", creator.key().as_ref(), vesting_campaign.mint.as_ref(), vesting_campaign.campaign_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub vesting_campaign: Account<'info, VestingCampaign>,
+
+    /// 代币mint
+    #[account(
+        constraint = mint.key() == vesting_campaign.mint @ VestingError::InvalidTokenMint
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// 活动托管代币账户
+    #[account(
+        mut,
+        seeds = [b"campaign_vault", vesting_campaign.key().as_ref()],
+        bump
+    )]
+    pub campaign_vault: InterfaceAccount<'info, TokenAccount>,
+
+    /// 创建者的代币账户（用于接收剩余代币）
+    #[account(
+        mut,
+        constraint = creator_token_account.mint == vesting_campaign.mint @ VestingError::InvalidTokenMint,
+        constraint = creator_token_account.owner == creator.key() @ VestingError::Unauthorized
+    )]
+    pub creator_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
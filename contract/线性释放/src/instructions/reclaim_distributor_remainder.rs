@@ -0,0 +1,101 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self, Mint, TokenInterface, TokenAccount, TransferChecked};
+use crate::state::MerkleDistributor;
+use crate::errors::VestingError;
+use crate::events::RemainderReclaimed;
+
+/// 取回 Merkle 分发中无人领取的剩余代币（仅创建者可用，须在领取截止后调用）
+pub fn reclaim_distributor_remainder(ctx: Context<ReclaimDistributorRemainder>) -> Result<()> {
+    let clock = Clock::get()?;
+    let distributor = &ctx.accounts.distributor;
+
+    require!(
+        distributor.is_claim_closed(clock.unix_timestamp),
+        VestingError::ClaimDeadlineNotReached
+    );
+
+    let remaining_amount = distributor.get_remaining_amount();
+    require!(remaining_amount > 0, VestingError::NothingToReclaim);
+
+    // 生成分发PDA签名种子
+    let distributor_id_bytes = distributor.distributor_id.to_le_bytes();
+    let seeds = &[
+        b"distributor",
+        distributor.creator.as_ref(),
+        distributor.mint.as_ref(),
+        distributor_id_bytes.as_ref(),
+        &[ctx.bumps.distributor],
+    ];
+    let signer_seeds = &[&seeds[..]];
+
+    // 将未领取的代币退还给创建者
+    let transfer_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        TransferChecked {
+            from: ctx.accounts.distributor_vault.to_account_info(),
+            mint: ctx.accounts.mint.to_account_info(),
+            to: ctx.accounts.creator_token_account.to_account_info(),
+            authority: ctx.accounts.distributor.to_account_info(),
+        },
+        signer_seeds,
+    );
+    token_interface::transfer_checked(transfer_ctx, remaining_amount, ctx.accounts.mint.decimals)?;
+
+    // 注资总额收缩为已领取金额（截止后本就不能再领取）
+    let distributor = &mut ctx.accounts.distributor;
+    distributor.total_amount = distributor.allocated_amount;
+
+    emit!(RemainderReclaimed {
+        source: distributor.key(),
+        amount: remaining_amount,
+        recipient: ctx.accounts.creator.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Merkle distributor #{} reclaimed {} unclaimed tokens",
+        distributor.distributor_id,
+        remaining_amount
+    );
+    Ok(())
+}
+
+/// 取回分发剩余代币的账户验证（仅创建者可用）
+#[derive(Accounts)]
+pub struct ReclaimDistributorRemainder<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    /// Merkle 分发账户
+    #[account(
+        mut,
+        constraint = distributor.creator == creator.key() @ VestingError::Unauthorized,
+        seeds = [b"distributor", creator.key().as_ref(), distributor.mint.as_ref(), distributor.distributor_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub distributor: Account<'info, MerkleDistributor>,
+
+    /// 代币mint
+    #[account(
+        constraint = mint.key() == distributor.mint @ VestingError::InvalidTokenMint
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// 分发托管代币账户
+    #[account(
+        mut,
+        seeds = [b"distributor_vault", distributor.key().as_ref()],
+        bump
+    )]
+    pub distributor_vault: InterfaceAccount<'info, TokenAccount>,
+
+    /// 创建者的代币账户（用于接收剩余代币）
+    #[account(
+        mut,
+        constraint = creator_token_account.mint == distributor.mint @ VestingError::InvalidTokenMint,
+        constraint = creator_token_account.owner == creator.key() @ VestingError::Unauthorized
+    )]
+    pub creator_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
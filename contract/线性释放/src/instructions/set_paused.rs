@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+use crate::state::VestingConfig;
+use crate::errors::VestingError;
+use crate::events::PauseStatusChanged;
+
+/// 暂停或恢复所有提取（仅管理员可用）
+pub fn set_paused(ctx: Context<SetPaused>, paused: bool) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    let clock = Clock::get()?;
+
+    config.paused = paused;
+
+    emit!(PauseStatusChanged {
+        admin: ctx.accounts.admin.key(),
+        paused,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Vesting claims paused: {}", paused);
+    Ok(())
+}
+
+/// 暂停或恢复提取的账户验证
+#[derive(Accounts)]
+pub struct SetPaused<'info> {
+    pub admin: Signer<'info>,
+
+    /// 全局配置账户
+    #[account(
+        mut,
+        constraint = config.admin == admin.key() @ VestingError::Unauthorized,
+        seeds = [b"config"],
+        bump
+    )]
+    pub config: Account<'info, VestingConfig>,
+}
@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+use crate::state::{VestingConfig, VestingSchedule};
+use crate::errors::VestingError;
+use crate::events::ScheduleFreezeChanged;
+
+/// 冻结或解冻单个释放计划（仅管理员可用，用于争议处理）
+pub fn set_schedule_frozen(ctx: Context<SetScheduleFrozen>, frozen: bool) -> Result<()> {
+    let vesting_schedule = &mut ctx.accounts.vesting_schedule;
+    let clock = Clock::get()?;
+
+    vesting_schedule.frozen = frozen;
+
+    emit!(ScheduleFreezeChanged {
+        vesting_schedule: vesting_schedule.key(),
+        admin: ctx.accounts.admin.key(),
+        frozen,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Vesting schedule {} frozen: {}", vesting_schedule.key(), frozen);
+    Ok(())
+}
+
+/// 冻结或解冻释放计划的账户验证
+#[derive(Accounts)]
+pub struct SetScheduleFrozen<'info> {
+    pub admin: Signer<'info>,
+
+    /// 全局配置账户
+    #[account(
+        constraint = config.admin == admin.key() @ VestingError::Unauthorized,
+        seeds = [b"config"],
+        bump
+    )]
+    pub config: Account<'info, VestingConfig>,
+
+    /// 释放计划账户
+    #[account(
+        mut,
+        seeds = [
+            vesting_schedule.seed_prefix(),
+            vesting_schedule.seed_authority().as_ref(),
+            vesting_schedule.initial_beneficiary.as_ref(),
+            vesting_schedule.mint.as_ref(),
+            vesting_schedule.schedule_id_seed().as_ref(),
+        ],
+        bump
+    )]
+    pub vesting_schedule: Account<'info, VestingSchedule>,
+}
@@ -0,0 +1,84 @@
+use anchor_lang::prelude::*;
+use crate::state::{VestingSchedule, VoterWeightRecord};
+use crate::errors::VestingError;
+use crate::events::VoteDelegateChanged;
+use crate::utils::sync_voter_weight;
+
+/// 设置或取消释放计划的治理投票权委托（仅受益人可用）
+/// 传入新的投票权重记录时委托给该记录的委托人，不传时取消委托
+pub fn set_vote_delegate(ctx: Context<SetVoteDelegate>) -> Result<()> {
+    let clock = Clock::get()?;
+    let accounts = &mut *ctx.accounts;
+
+    if let (Some(old_record), Some(new_record)) =
+        (&accounts.old_voter_weight_record, &accounts.new_voter_weight_record)
+    {
+        require!(old_record.key() != new_record.key(), VestingError::InvalidVoterWeightRecord);
+    }
+
+    // 从原委托人的记录中移除本计划的权重
+    sync_voter_weight(&mut accounts.vesting_schedule, accounts.old_voter_weight_record.as_mut(), 0)?;
+
+    let vesting_schedule = &mut accounts.vesting_schedule;
+    vesting_schedule.delegated_weight = 0;
+    vesting_schedule.voter_weight_record = match &accounts.new_voter_weight_record {
+        Some(new_record) => {
+            require!(
+                new_record.governing_token_mint == vesting_schedule.mint,
+                VestingError::InvalidVoterWeightRecord
+            );
+            new_record.key()
+        }
+        None => Pubkey::default(),
+    };
+
+    // 将本计划的权重计入新委托人的记录
+    let voting_weight = vesting_schedule.get_voting_weight();
+    sync_voter_weight(vesting_schedule, accounts.new_voter_weight_record.as_mut(), voting_weight)?;
+
+    emit!(VoteDelegateChanged {
+        vesting_schedule: vesting_schedule.key(),
+        beneficiary: accounts.beneficiary.key(),
+        voter_weight_record: vesting_schedule.voter_weight_record,
+        weight: vesting_schedule.delegated_weight,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Vote delegation of schedule {} set to record {} with weight {}",
+        vesting_schedule.key(),
+        vesting_schedule.voter_weight_record,
+        vesting_schedule.delegated_weight
+    );
+    Ok(())
+}
+
+/// 设置治理投票权委托的账户验证（仅受益人可用）
+#[derive(Accounts)]
+pub struct SetVoteDelegate<'info> {
+    pub beneficiary: Signer<'info>,
+
+    /// 释放计划账户
+    #[account(
+        mut,
+        constraint = !vesting_schedule.is_native() @ VestingError::InvalidTokenMint,
+        constraint = vesting_schedule.beneficiary == beneficiary.key() @ VestingError::Unauthorized,
+        seeds = [
+            vesting_schedule.seed_prefix(),
+            vesting_schedule.seed_authority().as_ref(),
+            vesting_schedule.initial_beneficiary.as_ref(),
+            vesting_schedule.mint.as_ref(),
+            vesting_schedule.schedule_id_seed().as_ref(),
+        ],
+        bump
+    )]
+    pub vesting_schedule: Account<'info, VestingSchedule>,
+
+    /// 当前委托的投票权重记录（已委托时必须传入）
+    #[account(mut)]
+    pub old_voter_weight_record: Option<Account<'info, VoterWeightRecord>>,
+
+    /// 新委托的投票权重记录（不传表示取消委托）
+    #[account(mut)]
+    pub new_voter_weight_record: Option<Account<'info, VoterWeightRecord>>,
+}
@@ -0,0 +1,145 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self, Mint, TokenInterface, TokenAccount, TransferChecked};
+use crate::state::{VestingSchedule, VestingPeriod, VoterWeightRecord};
+use crate::errors::VestingError;
+use crate::events::VestingToppedUp;
+use crate::utils::{sync_voter_weight, check_sufficient_balance, calculate_amount_per_period, get_received_amount, MAX_CALENDAR_MONTHS};
+
+/// 追加释放计划金额并可延长周期数（仅创建者可用）
+/// 每周期金额按新的总金额和周期数重新计算，已释放的金额不能因此减少
+pub fn top_up_vesting(
+    ctx: Context<TopUpVesting>,
+    additional_amount: u64,
+    additional_periods: u32,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let vesting_schedule = &ctx.accounts.vesting_schedule;
+
+    require!(additional_amount > 0, VestingError::InvalidAmount);
+
+    // 自定义解锁表按里程碑释放，不能延长周期数
+    if vesting_schedule.vesting_period == VestingPeriod::Custom {
+        require!(additional_periods == 0, VestingError::InvalidPeriodCount);
+    }
+
+    let period_count = vesting_schedule
+        .period_count
+        .checked_add(additional_periods)
+        .ok_or(VestingError::MathOverflow)?;
+
+    // 按日历释放时限制总月数
+    if let Some(months) = vesting_schedule.vesting_period.to_months() {
+        require!(
+            months * period_count as i64 <= MAX_CALENDAR_MONTHS,
+            VestingError::InvalidPeriodCount
+        );
+    }
+
+    // 检查创建者代币账户余额
+    check_sufficient_balance(&ctx.accounts.creator_token_account, additional_amount)?;
+
+    // 记录追加前的已释放金额
+    let vested_before = vesting_schedule.get_vested_amount(clock.unix_timestamp)?;
+
+    // 转移追加的代币到托管账户
+    let vault_balance_before = ctx.accounts.vault_token_account.amount;
+    let transfer_ctx = CpiContext::new(
+        ctx.accounts.token_program.to_account_info(),
+        TransferChecked {
+            from: ctx.accounts.creator_token_account.to_account_info(),
+            mint: ctx.accounts.mint.to_account_info(),
+            to: ctx.accounts.vault_token_account.to_account_info(),
+            authority: ctx.accounts.creator.to_account_info(),
+        },
+    );
+    token_interface::transfer_checked(transfer_ctx, additional_amount, ctx.accounts.mint.decimals)?;
+
+    // 按托管账户实际到账金额追加（Token-2022 转账手续费）
+    let received_amount = get_received_amount(&mut ctx.accounts.vault_token_account, vault_balance_before)?;
+
+    // 更新释放条款
+    let vesting_schedule = &mut ctx.accounts.vesting_schedule;
+    vesting_schedule.total_amount = vesting_schedule
+        .total_amount
+        .checked_add(received_amount)
+        .ok_or(VestingError::MathOverflow)?;
+    vesting_schedule.period_count = period_count;
+    vesting_schedule.amount_per_period = calculate_amount_per_period(
+        vesting_schedule.total_amount,
+        vesting_schedule.vesting_period,
+        period_count,
+    );
+
+    // 新条款下的已释放金额不能少于追加前
+    let vested_after = vesting_schedule.get_vested_amount(clock.unix_timestamp)?;
+    require!(vested_after >= vested_before, VestingError::TopUpReducesVested);
+
+    // 同步治理投票权重
+    let accounts = &mut *ctx.accounts;
+    let voting_weight = accounts.vesting_schedule.get_voting_weight();
+    sync_voter_weight(&mut accounts.vesting_schedule, accounts.voter_weight_record.as_mut(), voting_weight)?;
+    let vesting_schedule = &accounts.vesting_schedule;
+
+    emit!(VestingToppedUp {
+        vesting_schedule: vesting_schedule.key(),
+        added_amount: received_amount,
+        total_amount: vesting_schedule.total_amount,
+        period_count,
+        amount_per_period: vesting_schedule.amount_per_period,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Vesting schedule topped up by {} tokens: total {}, {} periods, {} per period",
+        received_amount,
+        vesting_schedule.total_amount,
+        period_count,
+        vesting_schedule.amount_per_period
+    );
+    Ok(())
+}
+
+/// 追加释放计划的账户验证（仅创建者可用）
+#[derive(Accounts)]
+pub struct TopUpVesting<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    /// 释放计划账户
+    #[account(
+        mut,
+        constraint = vesting_schedule.creator == creator.key() @ VestingError::Unauthorized,
+        seeds = [vesting_schedule.seed_prefix(), vesting_schedule.seed_authority().as_ref(), vesting_schedule.initial_beneficiary.as_ref(), vesting_schedule.mint.as_ref(), vesting_schedule.schedule_id_seed().as_ref()],
+        bump
+    )]
+    pub vesting_schedule: Account<'info, VestingSchedule>,
+
+    /// 代币mint
+    #[account(
+        constraint = mint.key() == vesting_schedule.mint @ VestingError::InvalidTokenMint
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// 创建者的代币账户
+    #[account(
+        mut,
+        constraint = creator_token_account.mint == vesting_schedule.mint @ VestingError::InvalidTokenMint,
+        constraint = creator_token_account.owner == creator.key() @ VestingError::Unauthorized
+    )]
+    pub creator_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// 托管代币账户
+    #[account(
+        mut,
+        constraint = vault_token_account.mint == vesting_schedule.mint @ VestingError::InvalidTokenMint,
+        seeds = [b"vault", vesting_schedule.key().as_ref()],
+        bump
+    )]
+    pub vault_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// 已委托治理投票权时传入对应的投票权重记录，用于同步权重
+    #[account(mut)]
+    pub voter_weight_record: Option<Account<'info, VoterWeightRecord>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+use crate::state::VestingConfig;
+use crate::errors::VestingError;
+use crate::events::AdminTransferred;
+
+/// 转移全局配置管理员（仅当前管理员可用）
+pub fn transfer_admin(ctx: Context<TransferAdmin>, new_admin: Pubkey) -> Result<()> {
+    require!(new_admin != Pubkey::default(), VestingError::Unauthorized);
+
+    let config = &mut ctx.accounts.config;
+    let clock = Clock::get()?;
+    let old_admin = config.admin;
+
+    config.admin = new_admin;
+
+    emit!(AdminTransferred {
+        old_admin,
+        new_admin,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Vesting admin transferred from {} to {}", old_admin, new_admin);
+    Ok(())
+}
+
+/// 转移管理员的账户验证
+#[derive(Accounts)]
+pub struct TransferAdmin<'info> {
+    pub admin: Signer<'info>,
+
+    /// 全局配置账户
+    #[account(
+        mut,
+        constraint = config.admin == admin.key() @ VestingError::Unauthorized,
+        seeds = [b"config"],
+        bump
+    )]
+    pub config: Account<'info, VestingConfig>,
+}
@@ -0,0 +1,88 @@
+use anchor_lang::prelude::*;
+use crate::state::{VestingSchedule, VoterWeightRecord};
+use crate::errors::VestingError;
+use crate::events::BeneficiaryTransferred;
+use crate::utils::{is_claims_paused, sync_voter_weight};
+
+/// 转移受益人（托管账户、已提取金额和释放条款保持不变）
+/// 已委托投票权时同时取消委托，新受益人需要重新设置
+pub fn transfer_beneficiary(ctx: Context<TransferBeneficiary>) -> Result<()> {
+    let clock = Clock::get()?;
+    let accounts = &mut *ctx.accounts;
+    let authority = accounts.authority.key();
+    let new_beneficiary = accounts.new_beneficiary.key();
+
+    // 全局暂停或计划冻结期间不能转移受益人
+    require!(!is_claims_paused(&accounts.config)?, VestingError::ClaimsPaused);
+    require!(!accounts.vesting_schedule.frozen, VestingError::ScheduleFrozen);
+
+    // 从原受益人委托的记录中移除本计划的权重
+    sync_voter_weight(&mut accounts.vesting_schedule, accounts.voter_weight_record.as_mut(), 0)?;
+
+    let vesting_schedule = &mut accounts.vesting_schedule;
+
+    // 当前受益人可转移；创建者仅在创建时允许的情况下可转移
+    require!(
+        authority == vesting_schedule.beneficiary
+            || (vesting_schedule.creator_can_transfer && authority == vesting_schedule.creator),
+        VestingError::Unauthorized
+    );
+
+    require!(
+        new_beneficiary != Pubkey::default() && new_beneficiary != vesting_schedule.beneficiary,
+        VestingError::InvalidBeneficiary
+    );
+
+    let old_beneficiary = vesting_schedule.beneficiary;
+    vesting_schedule.beneficiary = new_beneficiary;
+    vesting_schedule.voter_weight_record = Pubkey::default();
+    vesting_schedule.delegated_weight = 0;
+
+    emit!(BeneficiaryTransferred {
+        vesting_schedule: vesting_schedule.key(),
+        old_beneficiary,
+        new_beneficiary,
+        authority,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Beneficiary transferred from {} to {} by {}",
+        old_beneficiary,
+        new_beneficiary,
+        authority
+    );
+    Ok(())
+}
+
+/// 转移受益人的账户验证
+#[derive(Accounts)]
+pub struct TransferBeneficiary<'info> {
+    /// 当前受益人或创建者
+    pub authority: Signer<'info>,
+
+    /// CHECK: 全局配置账户PDA，尚未初始化时视为未暂停，已初始化时在指令中读取暂停状态
+    #[account(seeds = [b"config"], bump)]
+    pub config: UncheckedAccount<'info>,
+
+    /// 释放计划账户
+    #[account(
+        mut,
+        seeds = [
+            vesting_schedule.seed_prefix(),
+            vesting_schedule.seed_authority().as_ref(),
+            vesting_schedule.initial_beneficiary.as_ref(),
+            vesting_schedule.mint.as_ref(),
+            vesting_schedule.schedule_id_seed().as_ref(),
+        ],
+        bump
+    )]
+    pub vesting_schedule: Account<'info, VestingSchedule>,
+
+    /// CHECK: 新受益人钱包，不需要签名
+    pub new_beneficiary: UncheckedAccount<'info>,
+
+    /// 当前委托的投票权重记录（已委托时必须传入）
+    #[account(mut)]
+    pub voter_weight_record: Option<Account<'info, VoterWeightRecord>>,
+}
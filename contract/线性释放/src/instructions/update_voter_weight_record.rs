@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+use crate::state::VoterWeightRecord;
+
+/// 刷新投票权重记录的有效期（任何人可调用）
+/// 治理程序要求权重在当前slot内有效，投票前需在同一交易中调用
+pub fn update_voter_weight_record(ctx: Context<UpdateVoterWeightRecord>) -> Result<()> {
+    let voter_weight_record = &mut ctx.accounts.voter_weight_record;
+    let clock = Clock::get()?;
+
+    voter_weight_record.voter_weight_expiry = Some(clock.slot);
+    voter_weight_record.weight_action = None;
+    voter_weight_record.weight_action_target = None;
+
+    msg!(
+        "Voter weight of {} refreshed: {}",
+        voter_weight_record.governing_token_owner,
+        voter_weight_record.voter_weight
+    );
+    Ok(())
+}
+
+/// 刷新投票权重记录的账户验证
+#[derive(Accounts)]
+pub struct UpdateVoterWeightRecord<'info> {
+    /// 投票权重记录
+    #[account(
+        mut,
+        seeds = [
+            b"voter-weight-record",
+            voter_weight_record.realm.as_ref(),
+            voter_weight_record.governing_token_mint.as_ref(),
+            voter_weight_record.governing_token_owner.as_ref(),
+        ],
+        bump
+    )]
+    pub voter_weight_record: Account<'info, VoterWeightRecord>,
+}
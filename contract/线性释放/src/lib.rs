@@ -2,12 +2,14 @@ use anchor_lang::prelude::*;
 
 // 模块声明
 pub mod errors;
+pub mod events;
 pub mod instructions;
 pub mod state;
 pub mod utils;
 
 // 重新导出
 pub use errors::*;
+pub use events::*;
 pub use state::*;
 pub use utils::*;
 
@@ -23,12 +25,10 @@ pub mod vesting_project {
     /// 创建释放计划
     pub fn create_vesting_schedule(
         ctx: Context<CreateVestingSchedule>,
-        total_amount: u64,
-        start_time: i64,
-        vesting_period: VestingPeriod,
-        period_count: u32,
+        schedule_id: u64,
+        params: VestingParams,
     ) -> Result<()> {
-        instructions::create_vesting_schedule::create_vesting_schedule(ctx, total_amount, start_time, vesting_period, period_count)
+        instructions::create_vesting_schedule::create_vesting_schedule(ctx, schedule_id, params)
     }
 
     /// 提取已释放的代币
@@ -36,6 +36,16 @@ pub mod vesting_project {
         instructions::claim::claim(ctx)
     }
 
+    /// 提取指定数量的已释放代币
+    pub fn claim_partial(ctx: Context<Claim>, amount: u64) -> Result<()> {
+        instructions::claim::claim_partial(ctx, amount)
+    }
+
+    /// 代为提取（任何人可调用，代币只发到受益人的关联代币账户）
+    pub fn crank_claim(ctx: Context<CrankClaim>) -> Result<()> {
+        instructions::crank_claim::crank_claim(ctx)
+    }
+
     /// 查询可提取金额
     pub fn get_claimable_amount(ctx: Context<GetClaimableAmount>) -> Result<u64> {
         instructions::get_claimable_amount::get_claimable_amount(ctx)
@@ -46,8 +56,134 @@ pub mod vesting_project {
         instructions::get_vesting_info::get_vesting_info(ctx)
     }
 
-    /// 取消释放计划（仅创建者可用）
+    /// 取消释放计划（仅创建者可用，且计划必须可撤销）
     pub fn cancel_vesting(ctx: Context<CancelVesting>) -> Result<()> {
         instructions::cancel_vesting::cancel_vesting(ctx)
     }
+
+    /// 转移受益人（当前受益人可用，创建者在允许时可用）
+    pub fn transfer_beneficiary(ctx: Context<TransferBeneficiary>) -> Result<()> {
+        instructions::transfer_beneficiary::transfer_beneficiary(ctx)
+    }
+
+    /// 追加释放计划金额并可延长周期数（仅创建者可用）
+    pub fn top_up_vesting(ctx: Context<TopUpVesting>, additional_amount: u64, additional_periods: u32) -> Result<()> {
+        instructions::top_up_vesting::top_up_vesting(ctx, additional_amount, additional_periods)
+    }
+
+    /// 关闭已全部提取的释放计划，租金退还给创建者
+    pub fn close_vesting(ctx: Context<CloseVesting>) -> Result<()> {
+        instructions::close_vesting::close_vesting(ctx)
+    }
+
+    /// 创建原生 SOL 释放计划（lamports 托管在释放计划PDA中）
+    pub fn create_sol_vesting_schedule(
+        ctx: Context<CreateSolVestingSchedule>,
+        schedule_id: u64,
+        params: VestingParams,
+    ) -> Result<()> {
+        instructions::create_sol_vesting_schedule::create_sol_vesting_schedule(ctx, schedule_id, params)
+    }
+
+    /// 提取原生 SOL 释放计划中已释放的 lamports
+    pub fn claim_sol(ctx: Context<ClaimSol>) -> Result<()> {
+        instructions::claim_sol::claim_sol(ctx)
+    }
+
+    /// 取消原生 SOL 释放计划（仅创建者可用，且计划必须可撤销）
+    pub fn cancel_sol_vesting(ctx: Context<CancelSolVesting>) -> Result<()> {
+        instructions::cancel_sol_vesting::cancel_sol_vesting(ctx)
+    }
+
+    /// 关闭已全部提取的原生 SOL 释放计划
+    pub fn close_sol_vesting(ctx: Context<CloseSolVesting>) -> Result<()> {
+        instructions::close_sol_vesting::close_sol_vesting(ctx)
+    }
+
+    /// 为委托人创建投票权重记录
+    pub fn create_voter_weight_record(ctx: Context<CreateVoterWeightRecord>) -> Result<()> {
+        instructions::create_voter_weight_record::create_voter_weight_record(ctx)
+    }
+
+    /// 刷新投票权重记录的有效期
+    pub fn update_voter_weight_record(ctx: Context<UpdateVoterWeightRecord>) -> Result<()> {
+        instructions::update_voter_weight_record::update_voter_weight_record(ctx)
+    }
+
+    /// 设置或取消释放计划的治理投票权委托（仅受益人可用）
+    pub fn set_vote_delegate(ctx: Context<SetVoteDelegate>) -> Result<()> {
+        instructions::set_vote_delegate::set_vote_delegate(ctx)
+    }
+
+    /// 创建批量释放活动（一次性注资）
+    pub fn create_vesting_campaign(
+        ctx: Context<CreateVestingCampaign>,
+        campaign_id: u64,
+        params: VestingParams,
+    ) -> Result<()> {
+        instructions::create_vesting_campaign::create_vesting_campaign(ctx, campaign_id, params)
+    }
+
+    /// 从批量释放活动中分批添加受益人释放计划
+    pub fn add_campaign_schedules<'info>(
+        ctx: Context<'_, '_, 'info, 'info, AddCampaignSchedules<'info>>,
+        allocations: Vec<CampaignAllocation>,
+    ) -> Result<()> {
+        instructions::add_campaign_schedules::add_campaign_schedules(ctx, allocations)
+    }
+
+    /// 取回批量释放活动未分配的剩余代币
+    pub fn reclaim_campaign_remainder(ctx: Context<ReclaimCampaignRemainder>) -> Result<()> {
+        instructions::reclaim_campaign_remainder::reclaim_campaign_remainder(ctx)
+    }
+
+    /// 创建 Merkle 分发（发布 Merkle 根并注资）
+    pub fn create_merkle_distributor(
+        ctx: Context<CreateMerkleDistributor>,
+        distributor_id: u64,
+        root: [u8; 32],
+        total_amount: u64,
+        claim_deadline: i64,
+    ) -> Result<()> {
+        instructions::create_merkle_distributor::create_merkle_distributor(ctx, distributor_id, root, total_amount, claim_deadline)
+    }
+
+    /// 受益人首次从 Merkle 分发提取（验证证明并创建释放计划）
+    pub fn claim_from_distributor(
+        ctx: Context<ClaimFromDistributor>,
+        leaf: MerkleLeaf,
+        proof: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        instructions::claim_from_distributor::claim_from_distributor(ctx, leaf, proof)
+    }
+
+    /// 取回 Merkle 分发中无人领取的剩余代币（仅创建者可用）
+    pub fn reclaim_distributor_remainder(ctx: Context<ReclaimDistributorRemainder>) -> Result<()> {
+        instructions::reclaim_distributor_remainder::reclaim_distributor_remainder(ctx)
+    }
+
+    /// 初始化程序全局配置（仅程序升级权限账户可用）
+    pub fn initialize_config(ctx: Context<InitializeConfig>) -> Result<()> {
+        instructions::initialize_config::initialize_config(ctx)
+    }
+
+    /// 转移全局配置管理员（仅管理员可用）
+    pub fn transfer_admin(ctx: Context<TransferAdmin>, new_admin: Pubkey) -> Result<()> {
+        instructions::transfer_admin::transfer_admin(ctx, new_admin)
+    }
+
+    /// 暂停或恢复所有提取（仅管理员可用）
+    pub fn set_paused(ctx: Context<SetPaused>, paused: bool) -> Result<()> {
+        instructions::set_paused::set_paused(ctx, paused)
+    }
+
+    /// 冻结或解冻单个释放计划（仅管理员可用）
+    pub fn set_schedule_frozen(ctx: Context<SetScheduleFrozen>, frozen: bool) -> Result<()> {
+        instructions::set_schedule_frozen::set_schedule_frozen(ctx, frozen)
+    }
+
+    /// 将旧版释放计划账户迁移为当前布局（任何人可调用）
+    pub fn migrate_schedule(ctx: Context<MigrateSchedule>) -> Result<()> {
+        instructions::migrate_schedule::migrate_schedule(ctx)
+    }
 }
@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use crate::utils::{add_months, timestamp_to_ymd, calculate_amount_per_period};
 
 /// 释放周期类型
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
@@ -11,16 +12,54 @@ pub enum VestingPeriod {
     Yearly,
     /// 线性释放（按秒）
     Linear,
+    /// 自定义解锁表（按里程碑释放）
+    Custom,
 }
 
+/// 自定义解锁表的里程碑
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub struct Milestone {
+    /// 解锁时间（Unix时间戳）
+    pub timestamp: i64,
+    /// 截至该时间累计解锁比例（基点，10000 = 100%）
+    pub cumulative_bps: u16,
+}
+
+impl Milestone {
+    pub const LEN: usize = 8 + // timestamp
+        2; // cumulative_bps
+}
+
+/// 自定义解锁表最大里程碑数量
+pub const MAX_MILESTONES: usize = 24;
+
+/// 基点分母（10000 = 100%）
+pub const BPS_DENOMINATOR: u64 = 10_000;
+
+/// 原生 SOL 释放计划使用的 mint 标记（全零地址），托管的 lamports 直接存放在释放计划PDA中
+pub const NATIVE_SOL_MINT: Pubkey = Pubkey::new_from_array([0u8; 32]);
+
+/// 代为提取小费比例上限（基点，500 = 5%）
+pub const MAX_CRANK_TIP_BPS: u16 = 500;
+
 impl VestingPeriod {
-    /// 获取周期对应的秒数
+    /// 获取周期对应的秒数（按月/按年为近似值，实际释放时间按日历计算）
     pub fn to_seconds(&self) -> i64 {
         match self {
             VestingPeriod::Daily => 24 * 60 * 60,      // 1天 = 86400秒
             VestingPeriod::Monthly => 30 * 24 * 60 * 60, // 1月 = 30天
             VestingPeriod::Yearly => 365 * 24 * 60 * 60, // 1年 = 365天
             VestingPeriod::Linear => 1,                 // 线性释放按秒计算
+            VestingPeriod::Custom => 1,                 // 自定义解锁表按里程碑时间计算
+        }
+    }
+
+    /// 获取按日历计算的周期月数（按天/线性释放返回None）
+    pub fn to_months(&self) -> Option<i64> {
+        match self {
+            VestingPeriod::Monthly => Some(1),
+            VestingPeriod::Yearly => Some(12),
+            _ => None,
         }
     }
 }
@@ -30,22 +69,44 @@ impl VestingPeriod {
 pub struct VestingSchedule {
     /// 创建者
     pub creator: Pubkey,
-    /// 受益人
+    /// 受益人（当前）
     pub beneficiary: Pubkey,
+    /// 初始受益人（用于PDA种子，转移受益人后保持不变）
+    pub initial_beneficiary: Pubkey,
     /// 代币mint
     pub mint: Pubkey,
+    /// 计划编号（同一创建者/受益人/代币下区分多个计划，0 为默认计划）
+    pub schedule_id: u64,
     /// 总金额
     pub total_amount: u64,
     /// 已提取金额
     pub claimed_amount: u64,
     /// 开始时间（Unix时间戳）
     pub start_time: i64,
+    /// 锁定期时长（秒），锁定期结束前不释放，结束时一次性解锁锁定期内应释放的部分
+    pub cliff_duration: i64,
     /// 释放周期类型
     pub vesting_period: VestingPeriod,
     /// 释放周期数量（例如：12个月、365天、2年等）
     pub period_count: u32,
     /// 每个周期释放的金额
     pub amount_per_period: u64,
+    /// 自定义解锁表（仅 Custom 模式使用）
+    pub milestones: Vec<Milestone>,
+    /// 是否可撤销（不可撤销的计划不能被取消）
+    pub revocable: bool,
+    /// 创建者是否可以转移受益人
+    pub creator_can_transfer: bool,
+    /// 是否被管理员冻结（争议处理期间禁止提取）
+    pub frozen: bool,
+    /// 代为提取（crank）时调用者获得的小费比例（基点，按本次提取金额计算）
+    pub crank_tip_bps: u16,
+    /// 治理投票权委托的投票权重记录（未委托时为默认地址）
+    pub voter_weight_record: Pubkey,
+    /// 当前计入投票权重记录的数量
+    pub delegated_weight: u64,
+    /// 创建该计划的 Merkle 分发账户（普通计划为默认地址），Merkle 计划的 schedule_id 为叶子编号
+    pub distributor: Pubkey,
     /// 创建时间
     pub created_at: i64,
 }
@@ -54,19 +115,103 @@ impl VestingSchedule {
     pub const LEN: usize = 8 + // discriminator
         32 + // creator
         32 + // beneficiary
+        32 + // initial_beneficiary
         32 + // mint
+        8 +  // schedule_id
         8 +  // total_amount
         8 +  // claimed_amount
         8 +  // start_time
+        8 +  // cliff_duration
         1 +  // vesting_period (enum)
         4 +  // period_count
         8 +  // amount_per_period
+        4 + MAX_MILESTONES * Milestone::LEN + // milestones
+        1 +  // revocable
+        1 +  // creator_can_transfer
+        1 +  // frozen
+        2 +  // crank_tip_bps
+        32 + // voter_weight_record
+        8 +  // delegated_weight
+        32 + // distributor
         8;   // created_at
 
+    /// 是否由 Merkle 分发创建
+    pub fn is_from_distributor(&self) -> bool {
+        self.distributor != Pubkey::default()
+    }
+
+    /// PDA种子前缀：普通计划为 "vesting"，Merkle 计划为 "distributor_schedule"
+    pub fn seed_prefix(&self) -> &'static [u8] {
+        if self.is_from_distributor() {
+            b"distributor_schedule"
+        } else {
+            b"vesting"
+        }
+    }
+
+    /// PDA种子中的创建方：普通计划为创建者，Merkle 计划为分发账户
+    pub fn seed_authority(&self) -> &Pubkey {
+        if self.is_from_distributor() {
+            &self.distributor
+        } else {
+            &self.creator
+        }
+    }
+
+    /// 获取计划编号对应的PDA种子（Merkle 计划为叶子编号，始终使用 8 字节）
+    pub fn schedule_id_seed(&self) -> Vec<u8> {
+        if self.is_from_distributor() {
+            self.schedule_id.to_le_bytes().to_vec()
+        } else {
+            crate::utils::schedule_id_seed(self.schedule_id)
+        }
+    }
+
+    /// 是否为原生 SOL 释放计划
+    pub fn is_native(&self) -> bool {
+        self.mint == NATIVE_SOL_MINT
+    }
+
+    /// 是否已委托治理投票权
+    pub fn is_vote_delegated(&self) -> bool {
+        self.voter_weight_record != Pubkey::default()
+    }
+
+    /// 获取可用于治理投票的权重（托管账户中尚未提取的代币，含未释放部分）
+    pub fn get_voting_weight(&self) -> u64 {
+        self.total_amount.saturating_sub(self.claimed_amount)
+    }
+
+    /// 获取第 period 个周期结束（释放）的时间
+    /// 按月/按年释放按日历计算，每期在开始日期的同一天释放，当月没有该日时取当月最后一天
+    /// 自定义解锁表返回第 period 个里程碑的时间
+    pub fn get_period_release_time(&self, period: u32) -> i64 {
+        if self.vesting_period == VestingPeriod::Custom {
+            return match period {
+                0 => self.start_time,
+                _ => self.milestones[(period - 1) as usize].timestamp,
+            };
+        }
+
+        match self.vesting_period.to_months() {
+            Some(months) => add_months(self.start_time, months * period as i64),
+            None => self.start_time + self.vesting_period.to_seconds() * period as i64,
+        }
+    }
+
+    /// 获取截至当前时间累计解锁比例（基点，仅 Custom 模式使用）
+    pub fn get_unlocked_bps(&self, current_time: i64) -> u64 {
+        self.milestones
+            .iter()
+            .take_while(|milestone| milestone.timestamp <= current_time)
+            .last()
+            .map(|milestone| milestone.cumulative_bps as u64)
+            .unwrap_or(0)
+    }
+
     /// 获取总释放持续时间（秒）
     pub fn get_total_duration(&self) -> i64 {
-        let period_seconds = self.vesting_period.to_seconds();
-        period_seconds * self.period_count as i64
+        self.get_period_release_time(self.period_count) - self.start_time
     }
 
     /// 获取已完成的周期数
@@ -75,17 +220,54 @@ impl VestingSchedule {
             return 0;
         }
 
-        let elapsed_time = current_time - self.start_time;
-        let period_seconds = self.vesting_period.to_seconds();
+        if self.vesting_period == VestingPeriod::Custom {
+            // 自定义解锁表：已到达的里程碑数量
+            return self
+                .milestones
+                .iter()
+                .take_while(|milestone| milestone.timestamp <= current_time)
+                .count() as u32;
+        }
+
+        let completed_periods = match self.vesting_period.to_months() {
+            Some(months) => {
+                // 按日历月份差估算，日期未到时减去一期
+                let (start_year, start_month, _) = timestamp_to_ymd(self.start_time);
+                let (current_year, current_month, _) = timestamp_to_ymd(current_time);
+                let months_elapsed = (current_year - start_year) * 12 + (current_month - start_month);
+
+                let mut completed_periods = (months_elapsed / months).min(self.period_count as i64);
+                if completed_periods > 0
+                    && self.get_period_release_time(completed_periods as u32) > current_time
+                {
+                    completed_periods -= 1;
+                }
+                completed_periods
+            },
+            None => {
+                let elapsed_time = current_time - self.start_time;
+                let period_seconds = self.vesting_period.to_seconds();
+                elapsed_time / period_seconds
+            }
+        };
 
-        let completed_periods = elapsed_time / period_seconds;
         completed_periods.min(self.period_count as i64) as u32
     }
 
-    /// 获取可提取金额
-    pub fn get_claimable_amount(&self, current_time: i64) -> Result<u64> {
-        // 如果还没到开始时间，返回0
-        if current_time < self.start_time {
+    /// 获取锁定期结束时间
+    pub fn get_cliff_end_time(&self) -> i64 {
+        self.start_time + self.cliff_duration
+    }
+
+    /// 检查锁定期是否已结束
+    pub fn is_cliff_passed(&self, current_time: i64) -> bool {
+        current_time >= self.get_cliff_end_time()
+    }
+
+    /// 获取已释放金额（含已提取部分）
+    pub fn get_vested_amount(&self, current_time: i64) -> Result<u64> {
+        // 如果还没到开始时间或仍在锁定期内，返回0
+        if current_time < self.start_time || !self.is_cliff_passed(current_time) {
             return Ok(0);
         }
 
@@ -106,9 +288,25 @@ impl VestingSchedule {
                     vested_amount
                 }
             },
+            VestingPeriod::Custom => {
+                // 自定义解锁表：按已到达里程碑的累计比例计算
+                let unlocked_bps = self.get_unlocked_bps(current_time);
+                if unlocked_bps >= BPS_DENOMINATOR {
+                    self.total_amount
+                } else {
+                    (self.total_amount as u128)
+                        .checked_mul(unlocked_bps as u128)
+                        .ok_or(crate::errors::VestingError::MathOverflow)?
+                        .checked_div(BPS_DENOMINATOR as u128)
+                        .ok_or(crate::errors::VestingError::MathOverflow)? as u64
+                }
+            },
             _ => {
-                // 周期性释放：按完成的周期数计算
+                // 周期性释放：按完成的周期数计算，最后一期释放除不尽的余数
                 let completed_periods = self.get_completed_periods(current_time);
+                if completed_periods >= self.period_count {
+                    return Ok(self.total_amount);
+                }
                 let vested_amount = (completed_periods as u64)
                     .checked_mul(self.amount_per_period)
                     .ok_or(crate::errors::VestingError::MathOverflow)?;
@@ -116,6 +314,13 @@ impl VestingSchedule {
             }
         };
 
+        Ok(vested_amount)
+    }
+
+    /// 获取可提取金额
+    pub fn get_claimable_amount(&self, current_time: i64) -> Result<u64> {
+        let vested_amount = self.get_vested_amount(current_time)?;
+
         // 可提取金额 = 已释放金额 - 已提取金额
         vested_amount
             .checked_sub(self.claimed_amount)
@@ -142,7 +347,7 @@ impl VestingSchedule {
 
     /// 获取释放进度百分比 (0-100)
     pub fn get_vesting_progress(&self, current_time: i64) -> u8 {
-        if current_time < self.start_time {
+        if current_time < self.start_time || !self.is_cliff_passed(current_time) {
             return 0;
         }
 
@@ -157,6 +362,10 @@ impl VestingSchedule {
                 let progress = (elapsed_time as u128 * 100) / total_duration as u128;
                 progress.min(100) as u8
             },
+            VestingPeriod::Custom => {
+                let progress = self.get_unlocked_bps(current_time) * 100 / BPS_DENOMINATOR;
+                progress.min(100) as u8
+            },
             _ => {
                 let completed_periods = self.get_completed_periods(current_time);
                 let progress = (completed_periods as u128 * 100) / self.period_count as u128;
@@ -171,6 +380,11 @@ impl VestingSchedule {
             return None;
         }
 
+        // 锁定期内，下次释放时间为锁定期结束时间
+        if !self.is_cliff_passed(current_time) {
+            return Some(self.get_cliff_end_time());
+        }
+
         match self.vesting_period {
             VestingPeriod::Linear => {
                 // 线性释放没有固定的下次释放时间
@@ -180,8 +394,7 @@ impl VestingSchedule {
                 let completed_periods = self.get_completed_periods(current_time);
                 if completed_periods < self.period_count {
                     let next_period = completed_periods + 1;
-                    let period_seconds = self.vesting_period.to_seconds();
-                    Some(self.start_time + (next_period as i64 * period_seconds))
+                    Some(self.get_period_release_time(next_period))
                 } else {
                     None
                 }
@@ -189,3 +402,456 @@ impl VestingSchedule {
         }
     }
 }
+
+/// 旧版 VestingSchedule 账户布局（无计划编号、锁定期等字段），仅用于 migrate_schedule 读取
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct LegacyVestingSchedule {
+    pub creator: Pubkey,
+    pub beneficiary: Pubkey,
+    pub mint: Pubkey,
+    pub total_amount: u64,
+    pub claimed_amount: u64,
+    pub start_time: i64,
+    pub vesting_period: VestingPeriod,
+    pub period_count: u32,
+    pub amount_per_period: u64,
+    pub created_at: i64,
+}
+
+impl LegacyVestingSchedule {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // creator
+        32 + // beneficiary
+        32 + // mint
+        8 +  // total_amount
+        8 +  // claimed_amount
+        8 +  // start_time
+        1 +  // vesting_period (enum)
+        4 +  // period_count
+        8 +  // amount_per_period
+        8;   // created_at
+}
+
+/// 创建释放计划或批量活动时的释放条款
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct VestingParams {
+    /// 总释放金额（批量活动为注资总额）
+    pub total_amount: u64,
+    /// 开始时间（Unix时间戳）
+    pub start_time: i64,
+    /// 释放周期类型
+    pub vesting_period: VestingPeriod,
+    /// 释放周期数量（自定义解锁表时取里程碑数量）
+    pub period_count: u32,
+    /// 锁定期时长（秒）
+    pub cliff_duration: i64,
+    /// 是否可撤销
+    pub revocable: bool,
+    /// 创建者是否可以转移受益人
+    pub creator_can_transfer: bool,
+    /// 自定义解锁表，仅 Custom 模式使用
+    pub milestones: Vec<Milestone>,
+    /// 代为提取小费比例（基点），仅代币释放计划支持
+    pub crank_tip_bps: u16,
+}
+
+/// 批量释放分配项
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub struct CampaignAllocation {
+    /// 受益人
+    pub beneficiary: Pubkey,
+    /// 计划编号
+    pub schedule_id: u64,
+    /// 分配金额
+    pub amount: u64,
+}
+
+/// 批量释放活动账户（一次注资，按共享条款分批为受益人创建释放计划）
+#[account]
+pub struct VestingCampaign {
+    /// 创建者
+    pub creator: Pubkey,
+    /// 代币mint
+    pub mint: Pubkey,
+    /// 活动编号
+    pub campaign_id: u64,
+    /// 注资总额
+    pub total_amount: u64,
+    /// 已分配给受益人的金额
+    pub allocated_amount: u64,
+    /// 已创建的释放计划数量
+    pub schedule_count: u32,
+    /// 开始时间（Unix时间戳）
+    pub start_time: i64,
+    /// 锁定期时长（秒）
+    pub cliff_duration: i64,
+    /// 释放周期类型
+    pub vesting_period: VestingPeriod,
+    /// 释放周期数量
+    pub period_count: u32,
+    /// 自定义解锁表（仅 Custom 模式使用）
+    pub milestones: Vec<Milestone>,
+    /// 是否可撤销
+    pub revocable: bool,
+    /// 创建者是否可以转移受益人
+    pub creator_can_transfer: bool,
+    /// 创建时间
+    pub created_at: i64,
+}
+
+impl VestingCampaign {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // creator
+        32 + // mint
+        8 +  // campaign_id
+        8 +  // total_amount
+        8 +  // allocated_amount
+        4 +  // schedule_count
+        8 +  // start_time
+        8 +  // cliff_duration
+        1 +  // vesting_period (enum)
+        4 +  // period_count
+        4 + MAX_MILESTONES * Milestone::LEN + // milestones
+        1 +  // revocable
+        1 +  // creator_can_transfer
+        8;   // created_at
+
+    /// 获取未分配的剩余金额
+    pub fn get_remaining_amount(&self) -> u64 {
+        self.total_amount.saturating_sub(self.allocated_amount)
+    }
+
+    /// 按活动的共享条款生成受益人的释放计划
+    pub fn build_schedule(
+        &self,
+        beneficiary: Pubkey,
+        schedule_id: u64,
+        amount: u64,
+        created_at: i64,
+    ) -> VestingSchedule {
+        VestingSchedule {
+            creator: self.creator,
+            beneficiary,
+            initial_beneficiary: beneficiary,
+            mint: self.mint,
+            schedule_id,
+            total_amount: amount,
+            claimed_amount: 0,
+            start_time: self.start_time,
+            cliff_duration: self.cliff_duration,
+            vesting_period: self.vesting_period,
+            period_count: self.period_count,
+            amount_per_period: calculate_amount_per_period(amount, self.vesting_period, self.period_count),
+            milestones: self.milestones.clone(),
+            revocable: self.revocable,
+            creator_can_transfer: self.creator_can_transfer,
+            frozen: false,
+            crank_tip_bps: 0,
+            voter_weight_record: Pubkey::default(),
+            delegated_weight: 0,
+            distributor: Pubkey::default(),
+            created_at,
+        }
+    }
+}
+
+/// Merkle 分发账户（创建者发布 (受益人, 金额, 释放条款) 的 Merkle 根，受益人首次提取时创建释放计划）
+#[account]
+pub struct MerkleDistributor {
+    /// 创建者
+    pub creator: Pubkey,
+    /// 代币mint
+    pub mint: Pubkey,
+    /// 分发编号
+    pub distributor_id: u64,
+    /// Merkle 根
+    pub root: [u8; 32],
+    /// 注资总额
+    pub total_amount: u64,
+    /// 已转入受益人释放计划的金额
+    pub allocated_amount: u64,
+    /// 已创建的释放计划数量
+    pub schedule_count: u32,
+    /// 领取截止时间（截止后不能再领取，创建者可取回剩余代币）
+    pub claim_deadline: i64,
+    /// 创建时间
+    pub created_at: i64,
+}
+
+impl MerkleDistributor {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // creator
+        32 + // mint
+        8 +  // distributor_id
+        32 + // root
+        8 +  // total_amount
+        8 +  // allocated_amount
+        4 +  // schedule_count
+        8 +  // claim_deadline
+        8;   // created_at
+
+    /// 领取是否已截止
+    pub fn is_claim_closed(&self, current_time: i64) -> bool {
+        current_time > self.claim_deadline
+    }
+
+    /// 获取未被领取的剩余金额
+    pub fn get_remaining_amount(&self) -> u64 {
+        self.total_amount.saturating_sub(self.allocated_amount)
+    }
+}
+
+/// Merkle 分发的叶子（受益人为签名者，不在参数中）
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub struct MerkleLeaf {
+    /// 叶子编号（树内唯一）
+    pub leaf_index: u64,
+    /// 分配金额
+    pub amount: u64,
+    /// 开始时间（Unix时间戳）
+    pub start_time: i64,
+    /// 释放周期类型
+    pub vesting_period: VestingPeriod,
+    /// 释放周期数量
+    pub period_count: u32,
+    /// 锁定期时长（秒）
+    pub cliff_duration: i64,
+}
+
+/// Merkle 分发叶子的领取记录（每个叶子一个PDA，创建后即视为已领取，释放计划关闭后仍保留）
+#[account]
+pub struct ClaimStatus {
+    /// Merkle 分发账户
+    pub distributor: Pubkey,
+    /// 叶子编号
+    pub leaf_index: u64,
+    /// 领取的受益人
+    pub claimant: Pubkey,
+    /// 叶子分配金额
+    pub amount: u64,
+    /// 领取时间
+    pub claimed_at: i64,
+}
+
+impl ClaimStatus {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // distributor
+        8 +  // leaf_index
+        32 + // claimant
+        8 +  // amount
+        8;   // claimed_at
+}
+
+/// 程序全局配置账户
+#[account]
+pub struct VestingConfig {
+    /// 管理员
+    pub admin: Pubkey,
+    /// 是否暂停所有提取
+    pub paused: bool,
+}
+
+impl VestingConfig {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // admin
+        1;   // paused
+}
+
+/// 投票权重记录对应的治理操作（与 SPL Governance voter-weight addin 接口一致）
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum VoterWeightAction {
+    CastVote,
+    CommentProposal,
+    CreateGovernance,
+    CreateProposal,
+    SignOffProposal,
+}
+
+/// 投票权重记录（布局与 SPL Governance voter-weight addin 的 VoterWeightRecord 一致）
+/// 同一治理域、代币下每个委托人一个记录，汇总所有委托给该委托人的释放计划的权重
+#[account]
+pub struct VoterWeightRecord {
+    /// 治理域（Realm）
+    pub realm: Pubkey,
+    /// 治理代币mint
+    pub governing_token_mint: Pubkey,
+    /// 投票人（委托人）
+    pub governing_token_owner: Pubkey,
+    /// 投票权重
+    pub voter_weight: u64,
+    /// 权重有效的slot，治理程序要求在同一slot内刷新
+    pub voter_weight_expiry: Option<u64>,
+    /// 权重适用的治理操作（None 表示适用于所有操作）
+    pub weight_action: Option<VoterWeightAction>,
+    /// 权重适用的目标账户
+    pub weight_action_target: Option<Pubkey>,
+    /// 预留空间
+    pub reserved: [u8; 8],
+}
+
+impl VoterWeightRecord {
+    pub const LEN: usize = 8 + // discriminator
+        32 + // realm
+        32 + // governing_token_mint
+        32 + // governing_token_owner
+        8 +  // voter_weight
+        1 + 8 + // voter_weight_expiry
+        1 + 1 + // weight_action
+        1 + 32 + // weight_action_target
+        8;   // reserved
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DAY: i64 = 24 * 60 * 60;
+    const START: i64 = 1_700_000_000;
+
+    /// 构造按天释放、无锁定期的计划
+    fn daily_schedule(total_amount: u64, period_count: u32) -> VestingSchedule {
+        periodic_schedule(VestingPeriod::Daily, START, total_amount, period_count)
+    }
+
+    /// 构造指定周期类型、无锁定期的计划
+    fn periodic_schedule(
+        vesting_period: VestingPeriod,
+        start_time: i64,
+        total_amount: u64,
+        period_count: u32,
+    ) -> VestingSchedule {
+        VestingSchedule {
+            creator: Pubkey::default(),
+            beneficiary: Pubkey::default(),
+            initial_beneficiary: Pubkey::default(),
+            mint: Pubkey::default(),
+            schedule_id: 0,
+            total_amount,
+            claimed_amount: 0,
+            start_time,
+            cliff_duration: 0,
+            vesting_period,
+            period_count,
+            amount_per_period: calculate_amount_per_period(total_amount, vesting_period, period_count),
+            milestones: Vec::new(),
+            revocable: false,
+            creator_can_transfer: false,
+            frozen: false,
+            crank_tip_bps: 0,
+            voter_weight_record: Pubkey::default(),
+            delegated_weight: 0,
+            distributor: Pubkey::default(),
+            created_at: start_time,
+        }
+    }
+
+    #[test]
+    fn final_period_releases_remainder() {
+        let schedule = daily_schedule(10, 3);
+
+        assert_eq!(schedule.get_vested_amount(START).unwrap(), 0);
+        assert_eq!(schedule.get_vested_amount(START + DAY).unwrap(), 3);
+        assert_eq!(schedule.get_vested_amount(START + 2 * DAY).unwrap(), 6);
+        assert_eq!(schedule.get_vested_amount(START + 3 * DAY).unwrap(), 10);
+    }
+
+    #[test]
+    fn total_smaller_than_period_count_vests_at_end() {
+        let schedule = daily_schedule(2, 5);
+
+        assert_eq!(schedule.amount_per_period, 0);
+        assert_eq!(schedule.get_vested_amount(START + 4 * DAY).unwrap(), 0);
+        assert_eq!(schedule.get_vested_amount(START + 5 * DAY).unwrap(), 2);
+    }
+
+    #[test]
+    fn last_period_boundary() {
+        let schedule = daily_schedule(10, 3);
+        let end = START + 3 * DAY;
+
+        assert_eq!(schedule.get_vested_amount(end - 1).unwrap(), 6);
+        assert_eq!(schedule.get_vested_amount(end).unwrap(), 10);
+        assert_eq!(schedule.get_vested_amount(end + 100 * DAY).unwrap(), 10);
+        assert_eq!(schedule.get_claimable_amount(end).unwrap(), 10);
+    }
+
+    #[test]
+    fn monthly_final_period_releases_remainder() {
+        let schedule = periodic_schedule(VestingPeriod::Monthly, local_time(2024, 1, 31), 100, 3);
+        let last = schedule.get_period_release_time(3);
+
+        assert_eq!(schedule.amount_per_period, 33);
+        assert_eq!(schedule.get_vested_amount(schedule.get_period_release_time(1) - 1).unwrap(), 0);
+        assert_eq!(schedule.get_vested_amount(schedule.get_period_release_time(1)).unwrap(), 33);
+        assert_eq!(schedule.get_vested_amount(schedule.get_period_release_time(2)).unwrap(), 66);
+        assert_eq!(schedule.get_vested_amount(last - 1).unwrap(), 66);
+        assert_eq!(schedule.get_vested_amount(last).unwrap(), 100);
+        assert_eq!(timestamp_to_ymd(last), (2024, 4, 30));
+    }
+
+    #[test]
+    fn yearly_final_period_releases_remainder() {
+        let schedule = periodic_schedule(VestingPeriod::Yearly, local_time(2024, 2, 29), 1_000, 3);
+        let last = schedule.get_period_release_time(3);
+
+        assert_eq!(schedule.amount_per_period, 333);
+        assert_eq!(schedule.get_vested_amount(schedule.get_period_release_time(1)).unwrap(), 333);
+        assert_eq!(schedule.get_vested_amount(schedule.get_period_release_time(2)).unwrap(), 666);
+        assert_eq!(schedule.get_vested_amount(last - 1).unwrap(), 666);
+        assert_eq!(schedule.get_vested_amount(last).unwrap(), 1_000);
+    }
+
+    #[test]
+    fn final_period_releases_remainder_after_top_up() {
+        let mut schedule = daily_schedule(10, 3);
+        schedule.claimed_amount = schedule.get_vested_amount(START + 2 * DAY).unwrap();
+
+        // 与 top_up_vesting 相同：追加金额和周期数后重新计算每周期金额
+        schedule.total_amount += 7;
+        schedule.period_count += 2;
+        schedule.amount_per_period =
+            calculate_amount_per_period(schedule.total_amount, schedule.vesting_period, schedule.period_count);
+        let end = START + 5 * DAY;
+
+        assert_eq!(schedule.amount_per_period, 3);
+        assert_eq!(schedule.get_vested_amount(START + 4 * DAY).unwrap(), 12);
+        assert_eq!(schedule.get_vested_amount(end - 1).unwrap(), 12);
+        assert_eq!(schedule.get_vested_amount(end).unwrap(), 17);
+        assert_eq!(schedule.get_claimable_amount(end).unwrap(), 17 - 6);
+    }
+
+    /// 东八区某日 10:30 对应的时间戳，用于检查加月后时刻不变
+    fn local_time(year: i64, month: i64, day: i64) -> i64 {
+        crate::utils::date_to_timestamp(year, month, day) + 10 * 60 * 60 + 30 * 60
+    }
+
+    #[test]
+    fn add_month_from_jan_31_in_leap_year() {
+        let next = add_months(local_time(2024, 1, 31), 1);
+
+        assert_eq!(timestamp_to_ymd(next), (2024, 2, 29));
+        assert_eq!(next, local_time(2024, 2, 29));
+    }
+
+    #[test]
+    fn add_month_from_jan_31_in_non_leap_year() {
+        let next = add_months(local_time(2023, 1, 31), 1);
+
+        assert_eq!(timestamp_to_ymd(next), (2023, 2, 28));
+        assert_eq!(next, local_time(2023, 2, 28));
+    }
+
+    #[test]
+    fn add_twelve_months_from_feb_29() {
+        let next = add_months(local_time(2024, 2, 29), 12);
+
+        assert_eq!(timestamp_to_ymd(next), (2025, 2, 28));
+        assert_eq!(next, local_time(2025, 2, 28));
+    }
+
+    #[test]
+    fn add_month_rolls_december_into_january() {
+        assert_eq!(add_months(local_time(2023, 12, 15), 1), local_time(2024, 1, 15));
+        assert_eq!(timestamp_to_ymd(add_months(local_time(2023, 12, 31), 2)), (2024, 2, 29));
+    }
+}
@@ -1,7 +1,16 @@
 use anchor_lang::prelude::*;
-use crate::state::VestingSchedule;
+use anchor_lang::solana_program::keccak;
+use crate::state::{VestingSchedule, VestingPeriod, Milestone, MerkleLeaf, VestingConfig, VoterWeightRecord, MAX_MILESTONES, BPS_DENOMINATOR};
 use crate::errors::VestingError;
 
+pub const SECONDS_IN_DAY: i64 = 24 * 60 * 60;
+pub const DAYS_IN_MONTH: [i64; 12] = [31, 28, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31];
+pub const DAYS_IN_MONTH_LEAP_YEAR: [i64; 12] = [31, 29, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31];
+const UTC_OFFSET_SECONDS: i64 = 8 * 60 * 60; // UTC+8 (东八区)
+
+/// 按日历释放的最大月数（100年），避免日历计算消耗过多计算单元
+pub const MAX_CALENDAR_MONTHS: i64 = 100 * 12;
+
 /// 计算可提取金额的辅助函数
 pub fn calculate_claimable_amount(vesting_schedule: &VestingSchedule, current_time: i64) -> Result<u64> {
     vesting_schedule.get_claimable_amount(current_time)
@@ -33,17 +42,107 @@ pub fn validate_vesting_params_v2(
     Ok(())
 }
 
-/// 生成释放计划的PDA种子
+/// 计算每个周期释放的金额（向下取整，余数在最后一期一并释放）
+pub fn calculate_amount_per_period(total_amount: u64, vesting_period: VestingPeriod, period_count: u32) -> u64 {
+    match vesting_period {
+        VestingPeriod::Linear | VestingPeriod::Custom => {
+            // 线性释放和自定义解锁表不需要每周期金额，设为0
+            0
+        },
+        _ => {
+            // 周期性释放：总金额除以周期数
+            total_amount / period_count as u64
+        }
+    }
+}
+
+/// 验证锁定期时长（不能为负，且不能超过总释放时长）
+pub fn validate_cliff_duration(cliff_duration: i64, total_duration: i64) -> Result<()> {
+    require!(
+        cliff_duration >= 0 && cliff_duration <= total_duration,
+        VestingError::InvalidCliffDuration
+    );
+    Ok(())
+}
+
+/// 验证自定义解锁表
+/// 里程碑数量在 1..=MAX_MILESTONES 之间，时间不早于开始时间且严格递增，
+/// 累计比例单调不减且最后一个里程碑达到 100%
+pub fn validate_milestones(milestones: &[Milestone], start_time: i64) -> Result<()> {
+    require!(
+        !milestones.is_empty() && milestones.len() <= MAX_MILESTONES,
+        VestingError::InvalidMilestones
+    );
+
+    let mut last_timestamp = start_time;
+    let mut last_bps = 0u64;
+    for (index, milestone) in milestones.iter().enumerate() {
+        let bps = milestone.cumulative_bps as u64;
+        let timestamp_valid = if index == 0 {
+            milestone.timestamp >= last_timestamp
+        } else {
+            milestone.timestamp > last_timestamp
+        };
+        require!(timestamp_valid, VestingError::InvalidMilestones);
+        require!(bps >= last_bps && bps <= BPS_DENOMINATOR, VestingError::InvalidMilestones);
+        last_timestamp = milestone.timestamp;
+        last_bps = bps;
+    }
+
+    require!(last_bps == BPS_DENOMINATOR, VestingError::InvalidMilestones);
+    Ok(())
+}
+
+/// 计算 Merkle 分发的叶子哈希：keccak(叶子编号, 受益人, 金额, 开始时间, 释放周期类型, 周期数, 锁定期时长)
+pub fn compute_merkle_leaf(beneficiary: &Pubkey, leaf: &MerkleLeaf) -> [u8; 32] {
+    keccak::hashv(&[
+        &leaf.leaf_index.to_le_bytes(),
+        beneficiary.as_ref(),
+        &leaf.amount.to_le_bytes(),
+        &leaf.start_time.to_le_bytes(),
+        &[leaf.vesting_period as u8],
+        &leaf.period_count.to_le_bytes(),
+        &leaf.cliff_duration.to_le_bytes(),
+    ])
+    .to_bytes()
+}
+
+/// 验证 Merkle 证明（相邻节点按字节序从小到大拼接后哈希）
+pub fn verify_merkle_proof(proof: &[[u8; 32]], root: &[u8; 32], leaf: [u8; 32]) -> bool {
+    let mut computed_hash = leaf;
+    for proof_element in proof.iter() {
+        computed_hash = if computed_hash <= *proof_element {
+            keccak::hashv(&[&computed_hash, proof_element]).to_bytes()
+        } else {
+            keccak::hashv(&[proof_element, &computed_hash]).to_bytes()
+        };
+    }
+    computed_hash == *root
+}
+
+/// 生成计划编号的PDA种子
+/// 编号为0时返回空种子，派生出的地址与旧版（不含编号）的释放计划地址一致
+pub fn schedule_id_seed(schedule_id: u64) -> Vec<u8> {
+    if schedule_id == 0 {
+        Vec::new()
+    } else {
+        schedule_id.to_le_bytes().to_vec()
+    }
+}
+
+/// 生成释放计划的PDA种子（受益人为初始受益人）
 pub fn get_vesting_schedule_seeds<'a>(
     creator: &'a Pubkey,
     beneficiary: &'a Pubkey,
     mint: &'a Pubkey,
-) -> [&'a [u8]; 4] {
+    schedule_id_seed: &'a [u8],
+) -> [&'a [u8]; 5] {
     [
         b"vesting",
         creator.as_ref(),
         beneficiary.as_ref(),
         mint.as_ref(),
+        schedule_id_seed,
     ]
 }
 
@@ -71,6 +170,84 @@ pub fn calculate_vesting_progress(
     progress.min(100) as u8
 }
 
+fn is_leap_year(year: i64) -> bool {
+    (year % 4 == 0 && year % 100 != 0) || (year % 400 == 0)
+}
+
+/// 获取某年某月的天数
+pub fn days_in_month(year: i64, month: i64) -> i64 {
+    if is_leap_year(year) {
+        DAYS_IN_MONTH_LEAP_YEAR[(month - 1) as usize]
+    } else {
+        DAYS_IN_MONTH[(month - 1) as usize]
+    }
+}
+
+/// 参数为utc+0时间戳，得出结果为utc+8 日期 (年, 月, 日)
+pub fn timestamp_to_ymd(unix_timestamp: i64) -> (i64, i64, i64) {
+    let mut timestamp = unix_timestamp + UTC_OFFSET_SECONDS;
+    let mut year = 1970;
+
+    // 减去年份的秒数，直到找到具体年份
+    loop {
+        let year_seconds = if is_leap_year(year) { 366 } else { 365 } * SECONDS_IN_DAY;
+        if timestamp >= year_seconds {
+            timestamp -= year_seconds;
+            year += 1;
+        } else {
+            break;
+        }
+    }
+
+    // 找到月份
+    let mut month = 1;
+    loop {
+        let month_seconds = days_in_month(year, month) * SECONDS_IN_DAY;
+        if timestamp >= month_seconds {
+            timestamp -= month_seconds;
+            month += 1;
+        } else {
+            break;
+        }
+    }
+
+    let day = timestamp / SECONDS_IN_DAY + 1;
+
+    (year, month, day)
+}
+
+/// 日期转换为 Unix 时间戳，参数为utc+8 日期，得出utc+0 时间戳（当天0点）
+pub fn date_to_timestamp(year: i64, month: i64, day: i64) -> i64 {
+    let mut timestamp = 0;
+
+    // 加上前面的年数
+    for y in 1970..year {
+        timestamp += if is_leap_year(y) { 366 } else { 365 } * SECONDS_IN_DAY;
+    }
+
+    // 加上前面的月数
+    for m in 1..month {
+        timestamp += days_in_month(year, m) * SECONDS_IN_DAY;
+    }
+
+    // 加上天数
+    timestamp += SECONDS_IN_DAY * (day - 1);
+    timestamp - UTC_OFFSET_SECONDS
+}
+
+/// 在时间戳上增加指定月数，保持同一日期和时刻；目标月份没有该日时取当月最后一天
+pub fn add_months(timestamp: i64, months: i64) -> i64 {
+    let (year, month, day) = timestamp_to_ymd(timestamp);
+    let time_of_day = timestamp - date_to_timestamp(year, month, day);
+
+    let total_months = (month - 1) + months;
+    let new_year = year + total_months / 12;
+    let new_month = total_months % 12 + 1;
+    let new_day = day.min(days_in_month(new_year, new_month));
+
+    date_to_timestamp(new_year, new_month, new_day) + time_of_day
+}
+
 /// 格式化时间戳为可读字符串（用于日志）
 pub fn format_timestamp(timestamp: i64) -> String {
     format!("Unix timestamp: {}", timestamp)
@@ -78,7 +255,7 @@ pub fn format_timestamp(timestamp: i64) -> String {
 
 /// 验证代币账户所有权
 pub fn validate_token_account_owner(
-    token_account: &anchor_spl::token::TokenAccount,
+    token_account: &anchor_spl::token_interface::TokenAccount,
     expected_owner: &Pubkey,
 ) -> Result<()> {
     require!(
@@ -90,7 +267,7 @@ pub fn validate_token_account_owner(
 
 /// 验证代币账户mint
 pub fn validate_token_account_mint(
-    token_account: &anchor_spl::token::TokenAccount,
+    token_account: &anchor_spl::token_interface::TokenAccount,
     expected_mint: &Pubkey,
 ) -> Result<()> {
     require!(
@@ -100,9 +277,107 @@ pub fn validate_token_account_mint(
     Ok(())
 }
 
+/// 转账后重新读取托管账户余额，返回实际到账金额
+/// Token-2022 转账手续费扩展会从到账金额中扣除手续费，释放计划按实际到账金额记录
+pub fn get_received_amount<'info>(
+    vault_token_account: &mut InterfaceAccount<'info, anchor_spl::token_interface::TokenAccount>,
+    balance_before: u64,
+) -> Result<u64> {
+    vault_token_account.reload()?;
+    let received_amount = vault_token_account
+        .amount
+        .checked_sub(balance_before)
+        .ok_or(VestingError::MathOverflow)?;
+    require!(received_amount > 0, VestingError::InvalidAmount);
+    Ok(received_amount)
+}
+
+/// 创建PDA账户（转账补足租金 + allocate + assign，与 Anchor `init` 的做法一致）
+/// 不使用 create_account：目标地址预先被转入 lamports 时 create_account 会失败，任何人都能借此阻止创建
+pub fn create_pda_account<'info>(
+    payer: &AccountInfo<'info>,
+    new_account: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    space: u64,
+    owner: &Pubkey,
+    signer_seeds: &[&[u8]],
+) -> Result<()> {
+    let rent_lamports = Rent::get()?.minimum_balance(space as usize);
+    let top_up = rent_lamports.saturating_sub(new_account.lamports());
+    if top_up > 0 {
+        anchor_lang::system_program::transfer(
+            CpiContext::new(
+                system_program.clone(),
+                anchor_lang::system_program::Transfer {
+                    from: payer.clone(),
+                    to: new_account.clone(),
+                },
+            ),
+            top_up,
+        )?;
+    }
+    anchor_lang::system_program::allocate(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            anchor_lang::system_program::Allocate {
+                account_to_allocate: new_account.clone(),
+            },
+            &[signer_seeds],
+        ),
+        space,
+    )?;
+    anchor_lang::system_program::assign(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            anchor_lang::system_program::Assign {
+                account_to_assign: new_account.clone(),
+            },
+            &[signer_seeds],
+        ),
+        owner,
+    )
+}
+
+/// 读取全局暂停状态，配置账户尚未初始化（未调用 initialize_config）时视为未暂停
+pub fn is_claims_paused(config: &AccountInfo) -> Result<bool> {
+    if config.owner != &crate::ID || config.data_is_empty() {
+        return Ok(false);
+    }
+    let data = config.try_borrow_data()?;
+    let config = VestingConfig::try_deserialize(&mut &data[..])?;
+    Ok(config.paused)
+}
+
+/// 将释放计划计入投票权重记录的数量同步为 new_weight（未委托时忽略）
+/// 已委托时必须传入对应的投票权重记录
+pub fn sync_voter_weight<'info>(
+    vesting_schedule: &mut VestingSchedule,
+    voter_weight_record: Option<&mut Account<'info, VoterWeightRecord>>,
+    new_weight: u64,
+) -> Result<()> {
+    if !vesting_schedule.is_vote_delegated() {
+        return Ok(());
+    }
+
+    let voter_weight_record = voter_weight_record.ok_or(VestingError::InvalidVoterWeightRecord)?;
+    require!(
+        voter_weight_record.key() == vesting_schedule.voter_weight_record,
+        VestingError::InvalidVoterWeightRecord
+    );
+
+    voter_weight_record.voter_weight = voter_weight_record
+        .voter_weight
+        .checked_sub(vesting_schedule.delegated_weight)
+        .ok_or(VestingError::MathOverflow)?
+        .checked_add(new_weight)
+        .ok_or(VestingError::MathOverflow)?;
+    vesting_schedule.delegated_weight = new_weight;
+    Ok(())
+}
+
 /// 检查代币账户余额是否足够
 pub fn check_sufficient_balance(
-    token_account: &anchor_spl::token::TokenAccount,
+    token_account: &anchor_spl::token_interface::TokenAccount,
     required_amount: u64,
 ) -> Result<()> {
     require!(